@@ -0,0 +1,270 @@
+//! Methods for Jonathan Bowers' extension of the -illion system. Below the
+//! millillion (10^3003), names are the same as those of the Conway-Wechsler
+//! system in the short scale. Rather than chaining latin prefixes together
+//! beyond this, Bowers names each power of 1000 in the index of an -illion
+//! with a prefix of its own, taken from the SI prefixes and continued with
+//! Greek numbers: milli, micri, nani, pici, femti, atti, zepti, yocti, xoni,
+//! veci, and so on up to ennaennaconti for the 99th power.
+//!
+//! Thus, where Conway-Wechsler calls 10^3000003 "one millinillinillion", Bowers
+//! calls it "one micrillion". Each of these prefixes may be multiplied by a
+//! latin prefix before it, and is followed by the rest of the index, so that
+//! the 2,001st -illion is called the "bimillimillion".
+//!
+//! As Bowers' tiers are only named up to the 99th power of 1000, a power of
+//! ten whose -illion has an index of 10^300 or more gives
+//! `ParseError::InputTooLarge`.
+
+use std::fmt;
+use num_traits::cast::ToPrimitive;
+use num_traits::identities::Zero;
+
+use crate::common::{
+	latin_prefix,
+	name_string,
+	num_from_slice,
+	power_from_digits,
+	split_number,
+	write_myriad_words,
+	write_word
+};
+
+use crate::{NamingSystem, ParseError};
+
+// Prefixes for each power of 1000 in the index of an -illion, up to the 19th.
+// The prefixes from the 20th onwards are built from the two arrays below.
+static TIER_TWO_PREFIXES: [&str; 20] = [
+	"", "milli", "micri", "nani", "pici", "femti", "atti", "zepti", "yocti",
+	"xoni", "veci", "meci", "dueci", "treci", "tetreci", "penteci", "hexeci",
+	"hepteci", "okteci", "enneci"
+];
+
+static TIER_TWO_UNITS: [&str; 10] = [
+	"", "me", "due", "trio", "tetra", "penta", "hexa", "hepta", "okta", "enna"
+];
+
+static TIER_TWO_TENS: [&str; 10] = [
+	"", "", "icosi", "triaconti", "tetraconti", "pentaconti", "hexaconti",
+	"heptaconti", "oktaconti", "ennaconti"
+];
+
+// How many powers of 1000 may be named in the index of an -illion.
+const TIER_TWO_LIMIT: usize = 100;
+
+/// The Bowers -illion system as a `NamingSystem`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bowers;
+
+// Writes the prefix for some power of 1000 in the index of an -illion.
+fn write_tier_two<W: fmt::Write>(power: usize, out: &mut W) -> fmt::Result {
+	if power < TIER_TWO_PREFIXES.len() { return out.write_str(TIER_TWO_PREFIXES[power]); }
+	out.write_str(TIER_TWO_UNITS[power % 10])?;
+	out.write_str(TIER_TWO_TENS[power / 10])
+}
+
+// Writes the -illion with the given index, which is held as a string of
+// digits with no leading zeroes. The index must not be zero, as that is the
+// thousand rather than an -illion.
+fn write_illion<W: fmt::Write>(index: &str, out: &mut W) -> Result<(), ParseError> {
+	let groups = index.len().div_ceil(3);
+	if groups > TIER_TWO_LIMIT { return Err(ParseError::InputTooLarge); }
+
+	// Each group of three digits is named by a latin prefix, which for all but
+	// the last group multiplies the prefix of its power of 1000.
+	let mut start = 0;
+	for power in (0..groups).rev() {
+		let end = index.len() - 3*power;
+		let num = num_from_slice(index, start, end - start);
+		start = end;
+
+		if power == 0 {
+			if num == 0 { out.write_str("llion")?; }
+			else {
+				out.write_str(latin_prefix(num)?.as_str())?;
+				out.write_str("illion")?;
+			}
+		} else if num > 0 {
+			if num > 1 {
+				out.write_str(latin_prefix(num)?.as_str())?;
+				out.write_char('i')?;
+			}
+			write_tier_two(power, out)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Gives the full length name of a number in Bowers' -illion system. For
+/// numbers below 10^3003, this is the same as the Conway-Wechsler name in the
+/// short scale.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+///
+/// # Example
+///
+/// ```
+/// use googology::bowers::full_name;
+/// let digits = format!("42{}", "0".repeat(3003));
+/// let name = full_name(digits.as_str()).unwrap();
+/// assert_eq!("forty two millillion", name.as_str());
+/// ```
+pub fn full_name(digits: &str) -> Result<String, ParseError> {
+	name_string(|out| write_full_name(digits, out))
+}
+
+/// Writes the full length name of a number in the same manner as `full_name`.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err without writing
+///   anything.
+/// * `out` - The writer which the name is sent to, one word at a time.
+///
+/// # Example
+///
+/// ```
+/// use googology::bowers::write_full_name;
+/// let mut name = String::new();
+/// write_full_name("-7000000000", &mut name).unwrap();
+/// assert_eq!("minus seven billion", name.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(digits: &str, out: &mut W) -> Result<(), ParseError> {
+	let (negative, digits) = split_number(digits)?;
+	if digits.is_empty() {
+		out.write_str("zero")?;
+		return Ok(());
+	}
+
+	let mut first = true;
+	if negative { write_word("minus", &mut first, out)?; }
+
+	let groups = digits.len().div_ceil(3);
+	let mut start = 0;
+	for group in (0..groups).rev() {
+		let end = digits.len() - 3*group;
+		let num = num_from_slice(digits, start, end - start);
+		start = end;
+
+		if num == 0 { continue; }
+		write_myriad_words(num, &mut first, out)?;
+		match group {
+			0 => (),
+			1 => write_word("thousand", &mut first, out)?,
+			_ => {
+				out.write_char(' ')?;
+				write_illion((group - 1).to_string().as_str(), out)?;
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Gives a name for a number representing a power of ten. As the exponent is
+/// never stored as a number, it may be far larger than any number whose digits
+/// could be stored.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err. A negative exponent gives `ParseError::NegativeExponent`,
+///   and an exponent beyond the last of Bowers' tiers gives
+///   `ParseError::InputTooLarge`.
+///
+/// # Example
+///
+/// ```
+/// use googology::bowers::power_of_ten;
+/// let micrillion = power_of_ten("3000003").unwrap();
+/// let nanillion = power_of_ten("3000000005").unwrap();
+/// assert_eq!("one micrillion", micrillion.as_str());
+/// assert_eq!("one hundred nanillion", nanillion.as_str());
+/// ```
+pub fn power_of_ten(digits: &str) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?;
+	let small = power.to_usize().filter(|&p| p < 6);
+
+	let mut output = String::new();
+	if let Some(p) = small {
+		output.push_str(["one", "ten", "one hundred"][p % 3]);
+		if p >= 3 { output.push_str(" thousand"); }
+		return Ok(output);
+	}
+
+	// Each -illion is 1000 times the last, starting from 10^6.
+	let power = power - 3u32;
+	let index = &power / 3u32;
+	let rest = (&power % 3u32).to_usize().ok_or(ParseError::InternalError)?;
+	if index.is_zero() { return Err(ParseError::InternalError); }
+
+	output.push_str(["one ", "ten ", "one hundred "][rest]);
+	write_illion(index.to_str_radix(10).as_str(), &mut output)?;
+	Ok(output)
+}
+
+impl NamingSystem for Bowers {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::conway_wechsler::{self, Scale};
+
+	#[test]
+	fn small_names_match_conway_wechsler() -> Result<(), ParseError> {
+		for digits in ["0", "-0", "7", "1000", "1234567", "-42000000000000", "999000000000000000000001"] {
+			assert_eq!(conway_wechsler::full_name(digits, Scale::Short)?, full_name(digits)?);
+		}
+		for power in ["0", "2", "5", "6", "303", "3002"] {
+			assert_eq!(conway_wechsler::power_of_ten(power, Scale::Short)?, power_of_ten(power)?);
+		}
+
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("12a"));
+		assert_eq!(Err(ParseError::Empty), full_name(""));
+		Ok(())
+	}
+
+	#[test]
+	fn tier_two_names() -> Result<(), ParseError> {
+		assert_eq!("one millillion", power_of_ten("3003")?);
+		assert_eq!("one millimillion", power_of_ten("3006")?);
+		assert_eq!("ten millicentillion", power_of_ten("3304")?);
+		assert_eq!("one bimillillion", power_of_ten("6003")?);
+		assert_eq!("one decimillidecillion", power_of_ten("30033")?);
+		assert_eq!("one micrillion", power_of_ten("3000003")?);
+		assert_eq!("one micrimillimillion", power_of_ten("3003006")?);
+		assert_eq!("one picillion", power_of_ten("3000000000003")?);
+
+		// 10^(3*10^(3k)+3) for each of the named tiers.
+		let tier = |k: usize| power_of_ten(format!("3{}3", "0".repeat(3*k - 1)).as_str());
+		assert_eq!("one xonillion", tier(9)?);
+		assert_eq!("one vecillion", tier(10)?);
+		assert_eq!("one ennecillion", tier(19)?);
+		assert_eq!("one icosillion", tier(20)?);
+		assert_eq!("one meicosillion", tier(21)?);
+		assert_eq!("one trioicosillion", tier(23)?);
+		assert_eq!("one triacontillion", tier(30)?);
+		assert_eq!("one ennaennacontillion", tier(99)?);
+		assert_eq!(Err(ParseError::InputTooLarge), tier(100));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-3003"));
+
+		let digits = format!("5{}", "0".repeat(3006));
+		assert_eq!("five millimillion", Bowers.full_name(digits.as_str())?);
+		Ok(())
+	}
+}
@@ -0,0 +1,209 @@
+//! Methods for writing currency amounts in words, as on a cheque or in the
+//! legal amount of a contract. The whole units are named by the
+//! Conway-Wechsler system, so any scale may be used, while the minor units
+//! (such as cents) may be written either as a fraction or in words.
+//!
+//! For example, the amount "1234567.89" in dollars is written as "One million
+//! two hundred thirty-four thousand five hundred sixty-seven and 89/100
+//! dollars".
+
+use crate::common::split_decimal;
+use crate::conway_wechsler::{Scale, full_name_with};
+use crate::{Case, ParseError, Separator, Style};
+
+/// The names of a currency and of its minor unit, as used by `cheque_amount`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency<'a> {
+	/// The name of a single major unit, such as "dollar".
+	pub major_singular: &'a str,
+	/// The name of several major units, such as "dollars".
+	pub major_plural: &'a str,
+	/// The name of a single minor unit, such as "cent".
+	pub minor_singular: &'a str,
+	/// The name of several minor units, such as "cents".
+	pub minor_plural: &'a str,
+	/// How many digits of the amount come after the decimal point. This is
+	/// two for currencies with one hundred minor units to the major unit.
+	pub minor_digits: usize,
+}
+
+impl Currency<'static> {
+	/// United States dollars and cents.
+	pub const DOLLARS: Currency<'static> = Currency {
+		major_singular: "dollar",
+		major_plural: "dollars",
+		minor_singular: "cent",
+		minor_plural: "cents",
+		minor_digits: 2,
+	};
+
+	/// Euros and cents.
+	pub const EUROS: Currency<'static> = Currency {
+		major_singular: "euro",
+		major_plural: "euros",
+		minor_singular: "cent",
+		minor_plural: "cents",
+		minor_digits: 2,
+	};
+
+	/// Pounds sterling and pence.
+	pub const POUNDS: Currency<'static> = Currency {
+		major_singular: "pound",
+		major_plural: "pounds",
+		minor_singular: "penny",
+		minor_plural: "pence",
+		minor_digits: 2,
+	};
+}
+
+/// A parameter for `cheque_amount` which indicates how the minor units of an
+/// amount should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinorStyle {
+	/// The minor units are written as a fraction of the major unit, as in
+	/// "Twelve and 05/100 dollars". This is the usual style for cheques. As
+	/// the fraction is written even when it is zero, the amount is read as a
+	/// decimal, so the plural is used even for "One and 00/100 dollars". Only
+	/// a currency without minor units may use the singular.
+	Fraction,
+	/// The minor units are written in words, as in "Twelve dollars and five
+	/// cents". Nothing is written for the minor units if there are none.
+	Words,
+}
+
+/// Writes a currency amount in words, in the manner of a cheque. The name
+/// begins with a capital, compound tens are hyphenated, and "and" is only used
+/// before the minor units.
+///
+/// # Arguments
+///
+/// * `amount` - A string slice that holds the amount using only the digits
+///   0-9 and an optional decimal point. If any other character is present, this
+///   function will return an Err. If there are more digits after the decimal
+///   point than the currency has, `ParseError::LengthMismatch` is returned,
+///   and a negative amount gives `ParseError::NegativeNumber`.
+/// * `currency` - The names of the currency and its minor unit.
+/// * `minor` - Whether the minor units are written as a fraction or in words.
+/// * `scale` - The Conway-Wechsler scale to name the major units with.
+///
+/// # Example
+///
+/// ```
+/// use googology::cheque::{Currency, MinorStyle, cheque_amount};
+/// use googology::conway_wechsler::Scale;
+/// let dollars = Currency::DOLLARS;
+/// let cheque = cheque_amount("1234567.89", &dollars, MinorStyle::Fraction, Scale::Short).unwrap();
+/// let words = cheque_amount("21.01", &dollars, MinorStyle::Words, Scale::Short).unwrap();
+/// assert_eq!(
+///     "One million two hundred thirty-four thousand five hundred sixty-seven and 89/100 dollars",
+///     cheque.as_str()
+/// );
+/// assert_eq!("Twenty-one dollars and one cent", words.as_str());
+/// ```
+pub fn cheque_amount(
+	amount: &str,
+	currency: &Currency,
+	minor: MinorStyle,
+	scale: Scale
+) -> Result<String, ParseError> {
+	let (negative, whole, fraction) = split_decimal(amount)?;
+	if negative { return Err(ParseError::NegativeNumber); }
+	if fraction.len() > currency.minor_digits { return Err(ParseError::LengthMismatch); }
+
+	// An amount such as "12.5" has fifty cents rather than five.
+	let mut cents = String::from(fraction);
+	cents.push_str("0".repeat(currency.minor_digits - fraction.len()).as_str());
+
+	let style = Style {
+		hyphenate: true,
+		and: false,
+		separator: Separator::Space,
+		case: Case::Sentence,
+	};
+	let mut output = full_name_with(whole, scale, &style)?;
+	let is_one = |digits: &str| digits.trim_start_matches('0') == "1";
+
+	match minor {
+		MinorStyle::Fraction if cents.is_empty() => {
+			output.push(' ');
+			output.push_str(if is_one(whole) { currency.major_singular } else { currency.major_plural });
+		}
+		MinorStyle::Fraction => {
+			output.push_str(" and ");
+			output.push_str(cents.as_str());
+			output.push_str("/1");
+			output.push_str("0".repeat(currency.minor_digits).as_str());
+			output.push(' ');
+			output.push_str(currency.major_plural);
+		}
+		MinorStyle::Words => {
+			output.push(' ');
+			output.push_str(if is_one(whole) { currency.major_singular } else { currency.major_plural });
+
+			if cents.bytes().any(|b| b != b'0') {
+				let style = Style { case: Case::Lower, ..style };
+				output.push_str(" and ");
+				output.push_str(full_name_with(cents.as_str(), scale, &style)?.as_str());
+				output.push(' ');
+				output.push_str(if is_one(&cents) { currency.minor_singular } else { currency.minor_plural });
+			}
+		}
+	}
+
+	Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fraction_amounts() -> Result<(), ParseError> {
+		let cheque = |amount| cheque_amount(amount, &Currency::DOLLARS, MinorStyle::Fraction, Scale::Short);
+		assert_eq!(
+			"One million two hundred thirty-four thousand five hundred sixty-seven and 89/100 dollars",
+			cheque("1234567.89")?
+		);
+		assert_eq!("Twelve and 50/100 dollars", cheque("12.5")?);
+		assert_eq!("One and 00/100 dollars", cheque("1")?);
+		assert_eq!("Zero and 05/100 dollars", cheque(".05")?);
+		assert_eq!("Ninety-nine and 99/100 dollars", cheque("0099.99")?);
+
+		assert_eq!(Err(ParseError::LengthMismatch), cheque("1.001"));
+		assert_eq!(Err(ParseError::NegativeNumber), cheque("-1.00"));
+		assert_eq!(Err(ParseError::NegativeNumber), cheque("-0.01"));
+		assert_eq!(Err(ParseError::InvalidDigit), cheque("$1.00"));
+		assert_eq!(Err(ParseError::InvalidDigit), cheque("1,000.00"));
+		assert_eq!(Err(ParseError::Empty), cheque("."));
+		Ok(())
+	}
+
+	#[test]
+	fn word_amounts() -> Result<(), ParseError> {
+		let pounds = |amount| cheque_amount(amount, &Currency::POUNDS, MinorStyle::Words, Scale::LongBritish);
+		assert_eq!("One pound", pounds("1.00")?);
+		assert_eq!("Two pounds and one penny", pounds("2.01")?);
+		assert_eq!("Zero pounds and forty-two pence", pounds("0.42")?);
+		assert_eq!("One thousand million pounds", pounds("1000000000")?);
+
+		let yen = Currency {
+			major_singular: "yen",
+			major_plural: "yen",
+			minor_singular: "sen",
+			minor_plural: "sen",
+			minor_digits: 0,
+		};
+		assert_eq!("Five hundred yen", cheque_amount("500", &yen, MinorStyle::Fraction, Scale::Short)?);
+
+		// Without a fraction to read the amount as a decimal, the singular is
+		// chosen as it is for the words style.
+		let whole_dollars = Currency { minor_digits: 0, ..Currency::DOLLARS };
+		assert_eq!("One dollar", cheque_amount("1", &whole_dollars, MinorStyle::Fraction, Scale::Short)?);
+		assert_eq!("One dollar", cheque_amount("1", &whole_dollars, MinorStyle::Words, Scale::Short)?);
+		assert_eq!(Err(ParseError::LengthMismatch), cheque_amount("500.5", &yen, MinorStyle::Words, Scale::Short));
+
+		let euros = cheque_amount("3000000000.10", &Currency::EUROS, MinorStyle::Words, Scale::LongPeletier)?;
+		assert_eq!("Three milliard euros and ten cents", euros);
+		Ok(())
+	}
+}
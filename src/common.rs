@@ -3,10 +3,26 @@
  * used by Knuth.
  */
 
-use crate::ParseError;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Write as _};
+use std::io;
+use std::iter::Peekable;
+use std::str::FromStr;
+use num_bigint::BigUint;
+use num_traits::cast::ToPrimitive;
+use num_traits::identities::Zero;
+
+use crate::{Case, FractionStyle, MantissaStyle, ParseError, Rounding, Separator, Style, Word};
+
+// The most digits that a number may have when nothing else limits its size,
+// such as a number read back by a parse_name function, or one named by
+// chaining crores. A short name can describe a number with far more digits
+// than could ever be held in memory, so any larger number is refused before
+// anything is allocated for it.
+pub const MAX_PARSED_DIGITS: usize = 1 << 24;
 
 // Substrings used to construct names for the numbers 1-100.
-// These are used by the myriad_number function.
+// These are used by the myriad_words function.
 static NAMES_UPTO_TWENTY: [&str; 20] = [
 	"", "one", "two", "three", "four", "five", "six", "seven", "eight",
 	"nine", "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen",
@@ -40,9 +56,293 @@ static LATIN_HUNDREDS_PREFIXES: [&str; 10] = [
 	"sescenti", "septingenti", "octingenti", "nongenti"
 ];
 
+// Arrays used in the construction of greek prefixes for the Rowlett system.
+// As with the latin prefixes, each ends in a vowel which is later removed.
+// The base prefixes are used for values 0 - 9, the teens for 10 - 19, and the
+// rest for values 20 - 999, where any units are joined to the tens by "kai".
+static GREEK_BASE_PREFIXES: [&str; 10] = [
+	"", "", "mi", "gi", "tetra", "penta", "hexa", "hepta", "okta", "enna"
+];
+
+static GREEK_UNIT_PREFIXES: [&str; 10] = [
+	"", "hena", "di", "tri", "tetra", "penta", "hexa", "hepta", "okta", "enna"
+];
+
+static GREEK_TEEN_PREFIXES: [&str; 10] = [
+	"deka", "hendeka", "dodeka", "trisdeka", "tetradeka", "pentadeka",
+	"hexadeka", "heptadeka", "oktadeka", "enneadeka"
+];
+
+static GREEK_TENS_PREFIXES: [&str; 10] = [
+	"", "", "icosi", "triaconta", "tetraconta", "pentaconta", "hexaconta",
+	"heptaconta", "oktaconta", "enneaconta"
+];
+
+static GREEK_HUNDREDS_PREFIXES: [&str; 10] = [
+	"", "hecta", "diacosi", "triacosi", "tetracosi", "pentacosi", "hexacosi",
+	"heptacosi", "oktacosi", "enneacosi"
+];
+
+// Abbreviations for each of the latin prefixes above, used for compact names
+// such as "Qi" for quintillion and "UnDc" for undecillion. The base value for
+// zero is only used within chains, such as "M-N" for millinillion.
+static LATIN_BASE_ABBREVIATIONS: [&str; 10] = [
+	"N", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"
+];
+
+static LATIN_UNIT_ABBREVIATIONS: [&str; 10] = [
+	"", "Un", "Du", "Tr", "Qa", "Qi", "Sx", "Sp", "Oc", "No"
+];
+
+static LATIN_TENS_ABBREVIATIONS: [&str; 10] = [
+	"", "Dc", "Vg", "Tg", "Qd", "Qq", "Sg", "St", "Og", "Ng"
+];
+
+static LATIN_HUNDREDS_ABBREVIATIONS: [&str; 10] = [
+	"", "Ce", "Dn", "Tc", "Qe", "Qu", "Sc", "Si", "Oe", "Ne"
+];
+
 // Test an input string to see if it contains anything other than 0-9.
 pub fn is_all_digits(s: &str) -> bool {
-	s.chars().all(|c| c.is_digit(10))
+	s.chars().all(|c| c.is_ascii_digit())
+}
+
+// Splits an optional leading sign from a string of digits, giving back whether
+// the number is negative along with the rest of the string.
+pub fn split_sign(s: &str) -> (bool, &str) {
+	match s.as_bytes().first() {
+		Some(b'-') => (true, &s[1..]),
+		Some(b'+') => (false, &s[1..]),
+		_ => (false, s),
+	}
+}
+
+// Splits the sign from a number given to a full_name function and checks that
+// the rest is made only of digits, giving back whether the number is negative
+// along with its digits without any leading zeroes. There is no such thing as
+// negative zero, so zero is given back as an empty string of digits, for the
+// caller to name as plain zero.
+pub fn split_number(s: &str) -> Result<(bool, &str), ParseError> {
+	let (negative, digits) = split_sign(s);
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	if digits.is_empty() { return Err(ParseError::Empty); }
+	Ok((negative, digits.trim_start_matches('0')))
+}
+
+// Splits a decimal number such as "-12.5" into its sign, whole part and
+// fractional part, checking that both parts are made only of digits. A missing
+// whole part, as in ".5", is given back as "0".
+pub fn split_decimal(s: &str) -> Result<(bool, &str, &str), ParseError> {
+	let (negative, s) = split_sign(s);
+	let (whole, fraction) = match s.find('.') {
+		Some(i) => (&s[..i], &s[i+1..]),
+		None    => (s, ""),
+	};
+
+	if !is_all_digits(whole) || !is_all_digits(fraction) {
+		return Err(ParseError::InvalidDigit);
+	}
+	match (whole.is_empty(), fraction.is_empty()) {
+		(true, true)  => Err(ParseError::Empty),
+		(true, false) => Ok((negative, "0", fraction)),
+		_             => Ok((negative, whole, fraction)),
+	}
+}
+
+// Splits the sign from a number given to a function which can only name
+// numbers that are not negative, giving back the rest of the string. As
+// there is no such thing as negative zero, "-0" is allowed. Anything other
+// than digits is left for the caller to refuse.
+pub fn split_unsigned(s: &str) -> Result<&str, ParseError> {
+	let (negative, digits) = split_sign(s);
+	if negative && is_all_digits(digits) && digits.bytes().any(|b| b != b'0') {
+		return Err(ParseError::NegativeNumber);
+	}
+	Ok(digits)
+}
+
+// Reads the exponent given to a power_of_ten function into a BigUint. A sign
+// is allowed, but negative powers of ten are fractions, which are named by
+// the reciprocal_power_of_ten functions instead. This includes "-0", so that
+// every exponent with a minus sign is treated alike.
+pub fn power_from_digits(digits: &str) -> Result<BigUint, ParseError> {
+	// Sanity check. We want to convert our input string into a Bignum.
+	// The num_bigint crate doesn't quite allow us to know the cause of
+	// error, but from what we can tell, it's either an invalid digit or
+	// an empty string. So we'll make this clear in our own error.
+	let (negative, digits) = split_sign(digits);
+	let power = is_all_digits(digits)
+		.then_some(digits)
+		.ok_or(ParseError::InvalidDigit)
+		.and_then(|d| 
+			if d.is_empty() { Err(ParseError::Empty) }
+			else { Ok(d) }
+		)
+		.and_then(|d| BigUint::from_str(d).map_err(|_| ParseError::InternalError))?;
+
+	if negative { return Err(ParseError::NegativeExponent); }
+	Ok(power)
+}
+
+// Splits a number in scientific notation such as "6.022e23" into its mantissa
+// and exponent, giving back whether the exponent is negative along with its
+// value. A number without an exponent is given an exponent of zero.
+pub fn split_scientific(s: &str) -> Result<(&str, bool, BigUint), ParseError> {
+	let (mantissa, exponent) = match s.find(['e', 'E']) {
+		Some(i) => (&s[..i], &s[i+1..]),
+		None    => (s, "0"),
+	};
+
+	let (negative, exponent) = split_sign(exponent);
+	if !is_all_digits(exponent) { return Err(ParseError::InvalidDigit); }
+	if exponent.is_empty() { return Err(ParseError::Empty); }
+	let exponent = BigUint::from_str(exponent).map_err(|_| ParseError::InternalError)?;
+	Ok((mantissa, negative, exponent))
+}
+
+// Expands a mantissa and exponent into a plain decimal number, so that "6.022"
+// and 23 give "602200000000000000000000". Trailing zeroes after the decimal
+// point are dropped. Gives back None if more than max_digits digits would be
+// needed.
+pub fn expand_scientific(
+	mantissa: &str,
+	negative_exp: bool,
+	exponent: &BigUint,
+	max_digits: usize
+) -> Result<Option<String>, ParseError> {
+	let (negative, whole, fraction) = split_decimal(mantissa)?;
+	let whole = whole.trim_start_matches('0');
+	let digits = [whole, fraction].concat();
+	if digits.bytes().all(|b| b == b'0') { return Ok(Some(String::from("0"))); }
+
+	// Any exponent this large would need more than max_digits digits anyway.
+	let exponent = match exponent.to_usize() {
+		Some(e) if e <= max_digits.saturating_add(digits.len()) => e,
+		_ => return Ok(None),
+	};
+
+	let (int_part, frac_part) = match (negative_exp, whole.len()) {
+		(false, len) if len + exponent >= digits.len() => {
+			(digits.clone() + "0".repeat(len + exponent - digits.len()).as_str(), String::new())
+		}
+		(false, len) => {
+			(String::from(&digits[..len + exponent]), String::from(&digits[len + exponent..]))
+		}
+		(true, len) if exponent <= len => {
+			(String::from(&digits[..len - exponent]), String::from(&digits[len - exponent..]))
+		}
+		(true, len) => (String::new(), "0".repeat(exponent - len) + digits.as_str()),
+	};
+
+	let frac_part = frac_part.trim_end_matches('0');
+	if int_part.len() + frac_part.len() > max_digits { return Ok(None); }
+
+	let mut expanded = String::with_capacity(int_part.len() + frac_part.len() + 2);
+	if negative { expanded.push('-'); }
+	expanded.push_str(if int_part.is_empty() { "0" } else { int_part.as_str() });
+	if !frac_part.is_empty() {
+		expanded.push('.');
+		expanded.push_str(frac_part);
+	}
+	Ok(Some(expanded))
+}
+
+// Rounds a string of digits with no leading zeroes to sig_figs significant
+// figures (at least one, and at most every digit). Gives back the kept digits,
+// whether rounding up carried into a new leading digit (so that the number
+// gained a digit), and whether every dropped digit was a zero.
+pub fn round_digits(digits: &str, sig_figs: usize, rounding: Rounding) -> (Vec<u8>, bool, bool) {
+	let sig_figs = sig_figs.clamp(1, digits.len());
+	let mut mantissa = digits.as_bytes()[..sig_figs].to_vec();
+
+	// Decide whether to round up from the first dropped digit, and whether
+	// any of the digits after it are nonzero.
+	let next = digits.as_bytes().get(sig_figs).copied().unwrap_or(b'0');
+	let rest = digits.get(sig_figs + 1..).unwrap_or("").bytes().any(|b| b != b'0');
+	let exact = next == b'0' && !rest;
+	let round_up = match rounding {
+		Rounding::Down     => false,
+		Rounding::Up       => !exact,
+		Rounding::HalfUp   => next >= b'5',
+		Rounding::HalfEven => next > b'5' || (next == b'5' && (rest || mantissa[sig_figs - 1] % 2 == 1)),
+	};
+
+	let mut carried = false;
+	if round_up {
+		match mantissa.iter().rposition(|&b| b != b'9') {
+			Some(i) => {
+				mantissa[i] += 1;
+				mantissa[i+1..].iter_mut().for_each(|b| *b = b'0');
+			}
+			// Every digit was a nine, so the number gains a digit.
+			None => {
+				mantissa.iter_mut().for_each(|b| *b = b'0');
+				mantissa[0] = b'1';
+				carried = true;
+			}
+		}
+	}
+
+	(mantissa, carried, exact)
+}
+
+// A number rounded to some significant figures, as a count of some unit.
+// The unit is a power of the group size, such as 1000^unit.
+pub struct Approximation {
+	pub whole: String,
+	pub fraction: String,
+	pub unit: usize,
+	pub exact: bool,
+}
+
+impl Approximation {
+	// Gives the count of the unit as a decimal number, such as "1.2".
+	pub fn decimal(&self) -> String {
+		if self.fraction.is_empty() { return self.whole.clone(); }
+		format!("{}.{}", self.whole, self.fraction)
+	}
+}
+
+// Rounds a number to sig_figs significant figures (at least one), and splits
+// it into a count of the largest power of 10^group that is no larger than it.
+// Only the first few digits are looked at closely, while the rest are only
+// checked for being digits and whether any of them are nonzero.
+pub fn approximate(
+	digits: &str,
+	sig_figs: usize,
+	rounding: Rounding,
+	group: usize
+) -> Result<Approximation, ParseError> {
+	let digits = split_unsigned(digits)?;
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	if digits.is_empty() { return Err(ParseError::Empty); }
+
+	let digits = digits.trim_start_matches('0');
+	if digits.is_empty() {
+		return Ok(Approximation {
+			whole: String::from("0"),
+			fraction: String::new(),
+			unit: 0,
+			exact: true
+		});
+	}
+
+	let (mantissa, carried, exact) = round_digits(digits, sig_figs, rounding);
+	let len = digits.len() + carried as usize;
+
+	// Split the mantissa around the unit, padding the whole part with zeroes
+	// if there are fewer significant figures than digits before the unit.
+	let unit = (len - 1) / group;
+	let whole_len = len - unit * group;
+	let mut whole = String::with_capacity(whole_len);
+	let mut fraction = String::new();
+	for (i, &b) in mantissa.iter().enumerate() {
+		if i < whole_len { whole.push(b as char); } else { fraction.push(b as char); }
+	}
+	while whole.len() < whole_len { whole.push('0'); }
+	let fraction = String::from(fraction.trim_end_matches('0'));
+
+	Ok(Approximation { whole, fraction, unit, exact })
 }
 
 // Casts a slice of a string of all digits into usize.
@@ -52,6 +352,38 @@ pub fn num_from_slice(digits: &str, index: usize, ndigits: usize) -> usize {
 	digits.get(index..index+ndigits).unwrap().parse::<usize>().unwrap()
 }
 
+// Reads the next ndigits digits from a source of ASCII digits as a usize.
+// Unlike num_from_slice, the digits have not yet been checked, so this may
+// fail if some other character is found, or if the digits run out early.
+pub fn num_from_iter<I>(digits: &mut I, ndigits: usize) -> Result<usize, ParseError>
+where I: Iterator<Item = u8>
+{
+	(0..ndigits).try_fold(0, |num, _| match digits.next() {
+		Some(d @ b'0'..=b'9') => Ok(num * 10 + usize::from(d - b'0')),
+		Some(_) => Err(ParseError::InvalidDigit),
+		None => Err(ParseError::LengthMismatch),
+	})
+}
+
+// Skips over the leading zeroes in a source of len digits, giving back how
+// many digits remain afterwards. If every digit was a zero, the source must
+// also have come to an end.
+pub fn skip_leading_zeroes<I>(digits: &mut Peekable<I>, len: usize) -> Result<usize, ParseError>
+where I: Iterator<Item = u8>
+{
+	if len == 0 { return Err(ParseError::Empty); }
+
+	let mut remaining = len;
+	while remaining > 0 && digits.next_if_eq(&b'0').is_some() {
+		remaining -= 1;
+	}
+
+	if remaining == 0 && digits.peek().is_some() {
+		return Err(ParseError::LengthMismatch);
+	}
+	Ok(remaining)
+}
+
 // Provides a prefix for some "-illion" or "-yllion" number.
 // num should be some value between 0 and 999, or else None is returned
 // for sanity's sake. Number names with complex prefixes such as millinillion
@@ -118,30 +450,58 @@ pub fn latin_prefix(num: usize) -> Result<String, ParseError> {
 	Ok(prefix)
 }
 
-// Helper function for myriad number
-// Generates a name for a number in the range [0,99].
-// The name for the number is the empty string.
-fn name_hundreds(tens: usize, units: usize) -> String {
-	let mut output = String::from(TENS_NAMES[tens]);
+// Provides a greek prefix for some Rowlett "-illion" number, where the value
+// of num gives the power of 1000, such as "g" for the gillion (10^9). num
+// should be some value between 2 and 999, as the thousand has no prefix.
+pub fn greek_prefix(num: usize) -> Result<String, ParseError> {
+	if num >= 1000 { return Err(ParseError::InputTooLarge); }
+	if num < 2 { return Err(ParseError::InternalError); }
 
-	if output.is_empty() { 
-		output.push_str(NAMES_UPTO_TWENTY[(10*tens)+units]);
-	}
-	else if units > 0 {
-		output.push(' ');
-		output.push_str(NAMES_UPTO_TWENTY[units]);
+	let hs = num / 100;      // Hundreds place
+	let ts = num % 100 / 10; // Tens place
+	let us = num % 10;       // Units place
+
+	// Unlike the latin prefixes, the order is (hundreds)(tens)(units).
+	let mut prefix = String::from(GREEK_HUNDREDS_PREFIXES[hs]);
+	match ts {
+		0 if hs == 0 => prefix.push_str(GREEK_BASE_PREFIXES[us]),
+		0 => prefix.push_str(GREEK_UNIT_PREFIXES[us]),
+		1 => prefix.push_str(GREEK_TEEN_PREFIXES[us]),
+		_ => {
+			prefix.push_str(GREEK_TENS_PREFIXES[ts]);
+			if us > 0 {
+				prefix.push_str("kai");
+				prefix.push_str(GREEK_UNIT_PREFIXES[us]);
+			}
+		}
 	}
 
-	output
+	// As with latin_prefix, the vowel at the end is replaced by "illion".
+	prefix.pop();
+	Ok(prefix)
+}
+
+// Helper function for myriad_words.
+// Gives the words for a number in the range [0,99], of which there are at
+// most two. There are no words for the number zero.
+fn hundreds_words(tens: usize, units: usize) -> impl Iterator<Item = Word> {
+	let words = match TENS_NAMES[tens] {
+		"" => [NAMES_UPTO_TWENTY[(10*tens)+units], ""],
+		t  => [t, NAMES_UPTO_TWENTY[units]],
+	};
+
+	IntoIterator::into_iter(words)
+		.filter(|w| !w.is_empty())
+		.map(Word::Small)
 }
 
-// This function converts a number in the range [1, 9999] to English words.
+// This function gives the words for a number in the range [0, 9999].
 // Due to its use in the -yllion system, this function will not use the word
 // "thousand", however, instead preferring "ten hundred" or something similar.
 // Although this function is also used by Conway-Wechsler, which does use
 // thousands, this will not be a problem as that function will only use this
 // for three digit numbers.
-pub fn myriad_number(num: usize) -> Result<String, ParseError> {
+pub fn myriad_words(num: usize) -> Result<Vec<Word>, ParseError> {
 	if num >= 10000 {
 		// This should not be possible. 
 		return Err(ParseError::InternalError);
@@ -152,15 +512,906 @@ pub fn myriad_number(num: usize) -> Result<String, ParseError> {
 	let ts = num % 100 / 10;   // Tens place
 	let us = num % 10;         // Units place
 
-	let mut output = name_hundreds(ms, hs);
-	let     append = name_hundreds(ts, us);
+	let mut words = hundreds_words(ms, hs).collect::<Vec<Word>>();
+	if !words.is_empty() { words.push(Word::Hundred); }
+	words.extend(hundreds_words(ts, us));
+	Ok(words)
+}
+
+// Writes a word of a name, placing a space before it unless it is the first.
+pub fn write_word<W: fmt::Write>(word: &str, first: &mut bool, out: &mut W) -> fmt::Result {
+	if !*first { out.write_char(' ')?; }
+	*first = false;
+	out.write_str(word)
+}
 
-	if !output.is_empty() { output.push_str(" hundred "); }
-	if !append.is_empty() { 
-		output.push_str(append.as_str());
-		output.push(' ');
+// Writes the words for a number below ten thousand, as given by myriad_words,
+// in the same manner as write_word.
+pub fn write_myriad_words<W: fmt::Write>(
+	num: usize,
+	first: &mut bool,
+	out: &mut W
+) -> Result<(), ParseError> {
+	for word in myriad_words(num)? {
+		if !*first { out.write_char(' ')?; }
+		*first = false;
+		write!(out, "{}", word)?;
 	}
+	Ok(())
+}
+
+// Writes the latin prefixes for a Conway-Wechsler zillion, each ending in
+// "illi", starting from the most significant. For example, the value 1000
+// gives "millinilli", to which "on" or "ard" may be added.
+pub fn write_zillion_prefixes<W: fmt::Write>(power: usize, out: &mut W) -> fmt::Result {
+	let mut place = 1;
+	while place <= power / 1000 { place *= 1000; }
+
+	while place > 0 {
+		let prefix = latin_prefix(power / place % 1000).map_err(|_| fmt::Error)?;
+		out.write_str(prefix.as_str())?;
+		out.write_str("illi")?;
+		place /= 1000;
+	}
+
+	Ok(())
+}
+
+// Writes the abbreviations for the latin prefixes of a Conway-Wechsler
+// zillion, starting from the most significant and joined with '-'. For
+// example, the value 11 gives "UnDc" and the value 1000 gives "M-N".
+pub fn write_zillion_abbreviations<W: fmt::Write>(power: usize, out: &mut W) -> fmt::Result {
+	let mut place = 1;
+	while place <= power / 1000 { place *= 1000; }
+
+	let mut first = true;
+	while place > 0 {
+		let num = power / place % 1000;
+		if !first { out.write_char('-')?; }
+		if num < 10 {
+			out.write_str(LATIN_BASE_ABBREVIATIONS[num])?;
+		} else {
+			// As with latin_prefix, the order is (units)(tens)(hundreds).
+			out.write_str(LATIN_UNIT_ABBREVIATIONS[num % 10])?;
+			out.write_str(LATIN_TENS_ABBREVIATIONS[num % 100 / 10])?;
+			out.write_str(LATIN_HUNDREDS_ABBREVIATIONS[num / 100])?;
+		}
+		first = false;
+		place /= 1000;
+	}
+
+	Ok(())
+}
+
+// Gives the words for each group of digits in a full name, as read by
+// GroupWords. The digits of a number are read in groups of the same size,
+// except for the first group, which may be shorter.
+pub trait Groups {
+	// How many digits are in each group.
+	const SIZE: usize;
+
+	// Adds the words for a group with the value num, which has remaining
+	// digits after it.
+	fn group_words(
+		&mut self,
+		num: usize,
+		remaining: usize,
+		words: &mut VecDeque<Word>
+	) -> Result<(), ParseError>;
+}
+
+// Gives each word of a full name in turn, reading one group of digits at a
+// time from some source of digits. The words for each group are made only
+// once all of the words before them have been taken.
+pub struct GroupWords<I: Iterator<Item = u8>, G> {
+	digits: Peekable<I>,
+	remaining: usize,
+	size: usize,
+	groups: G,
+	pending: VecDeque<Word>,
+	done: bool,
+}
+
+impl<I: Iterator<Item = u8>, G: Groups> GroupWords<I, G> {
+	pub fn new(digits: I, len: usize, groups: G) -> Result<Self, ParseError> {
+		// We want to handle the case of leading zeroes. If all digits are
+		// zero, we want to just give the word "zero", and otherwise process
+		// from the first nonzero character.
+		let mut digits = digits.peekable();
+		let remaining = skip_leading_zeroes(&mut digits, len)?;
+
+		let mut pending = VecDeque::new();
+		if remaining == 0 { pending.push_back(Word::Small("zero")); }
+
+		// Determine how many digits are in the first group (e.g. 2 in the
+		// case of 12 tredecillion). The rest of the digits are handled in
+		// whole groups.
+		let size = match remaining % G::SIZE { 0 => G::SIZE, n => n };
+
+		Ok(GroupWords { digits, remaining, size, groups, pending, done: false })
+	}
+
+	// Reads groups of digits until there is some word to give, and queues
+	// up the words for that group.
+	fn read_group(&mut self) -> Result<(), ParseError> {
+		while self.pending.is_empty() && self.remaining > 0 {
+			let num = num_from_iter(&mut self.digits, self.size)?;
+			self.remaining -= self.size;
+			self.size = G::SIZE;
+			self.groups.group_words(num, self.remaining, &mut self.pending)?;
+		}
+
+		if self.remaining == 0 && self.digits.next().is_some() {
+			return Err(ParseError::LengthMismatch);
+		}
+		Ok(())
+	}
+}
+
+impl<I: Iterator<Item = u8>, G: Groups> Iterator for GroupWords<I, G> {
+	type Item = Result<Word, ParseError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done { return None; }
+
+		if let Err(e) = self.read_group() {
+			self.done = true;
+			return Some(Err(e));
+		}
+
+		let word = self.pending.pop_front();
+		self.done = word.is_none();
+		word.map(Ok)
+	}
+}
+
+// The parts of a naming system from which the rest of its names are made,
+// so that the functions for names such as ordinals and decimals are written
+// only once, here, rather than once for each system.
+pub trait NameParts {
+	type Groups: Groups;
+
+	// How many digits each unit of an approximate name is apart, such as 3
+	// for thousand, million and billion.
+	const UNIT_DIGITS: usize;
+
+	// Gives the groups to read the digits of a full name with.
+	fn groups(&self) -> Self::Groups;
+
+	// Writes the name for a power of ten.
+	fn write_power<W: fmt::Write>(&self, power: BigUint, out: &mut W) -> Result<(), ParseError>;
+
+	// Whether the name for a power of ten begins with "ten", as in "ten
+	// thousand".
+	fn begins_with_ten(&self, power: &BigUint) -> bool;
+
+	// Writes the name of the unit of an approximate name, which is the given
+	// power of 10^UNIT_DIGITS, with a space before it. A unit of zero has no
+	// name.
+	fn write_unit<W: fmt::Write>(&self, unit: usize, out: &mut W) -> Result<(), ParseError>;
+
+	// Gives each word of the full name of a number, whose len digits come
+	// from an iterator.
+	fn words<I>(&self, digits: I, len: usize) -> Result<GroupWords<I, Self::Groups>, ParseError>
+	where
+		I: Iterator<Item = u8>
+	{
+		GroupWords::new(digits, len, self.groups())
+	}
+}
+
+// Writes the full name of a number whose digits come from an iterator, with
+// a space between each word. As the digits are only checked as they are read,
+// an error may be found after some of the name has been written.
+pub fn write_name_from_iter<S, I, W>(
+	system: &S,
+	digits: I,
+	len: usize,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	S: NameParts,
+	I: Iterator<Item = u8>,
+	W: fmt::Write
+{
+	let mut written = false;
+	for word in system.words(digits, len)? {
+		if written { out.write_char(' ')?; }
+		write!(out, "{}", word?)?;
+		written = true;
+	}
+
+	Ok(())
+}
+
+// Create a word for an arbitrary grouping of four digits.
+// This function's behavior should not be considered perfectly equivalent to the
+// zillion_words function on the conway_wechsler module, because it is not
+// bijective. The number 12,0000,0042,0000 is given the full_name of
+// "twelve myriad myllion forty two myriad", indicating that the word "myriad"
+// is intended to be returned both for the grouping containing 42, as well as
+// the grouping containing 12.
+//
+// Note: This function also returns an integer to be compared against the
+// last_largest value in YllionGroups.
+fn zyllion_word(num: usize) -> (Option<Word>, usize) {
+	// The last grouping has no qualifier,
+	// and every other grouping is just "myriad".
+	if num == 0     { return (None, 0); }
+	if num % 2 == 1 { return (Some(Word::Myriad), 1); }
+
+	// For the rest, we want to find the greatest power of 2 that we're a
+	// multiple of, which gives the latin prefix to put before "yllion".
+	// Note that the greatest power of two should be in the range [1,63]
+	// by necessity, since num is an even-valued usize.
+	let greatest_power_of_two = num.trailing_zeros() as usize;
+	(Some(Word::Yllion(greatest_power_of_two)), greatest_power_of_two + 1)
+}
+
+// The groups of a Knuth -yllion full name, which are four digits each.
+// Because each zyllion term describes the quantity of the next largest term
+// (i.e. one myriad myllion), we keep track of the most recent largest term
+// we've given.
+#[derive(Default)]
+pub struct YllionGroups {
+	last_largest: usize,
+}
+
+impl Groups for YllionGroups {
+	const SIZE: usize = 4;
+
+	fn group_words(
+		&mut self,
+		num: usize,
+		remaining: usize,
+		words: &mut VecDeque<Word>
+	) -> Result<(), ParseError> {
+		let (zyllion, largest) = zyllion_word(remaining / 4);
+
+		if num > 0 {
+			words.extend(myriad_words(num)?);
+			if let Some(z) = zyllion {
+				words.push_back(z);
+				self.last_largest = largest;
+			}
+		}
+
+		// This condition does not trigger if we gave a zyllion in the
+		// above block of code. Instead, it means that we have a group
+		// of all zeroes, but should be giving a zyllion that is larger
+		// than the last one that we gave.
+		if largest > self.last_largest {
+			words.extend(zyllion);
+			self.last_largest = largest;
+		}
+		Ok(())
+	}
+}
+
+// A helper function for the Knuth -yllion system which handles the extremely
+// large yllions.
+// According to Knuth's essay, for large enough n, 10^(2^(n+2)) shall be wrapped
+// in a name such as "latin{word for n with spaces removed}yllion". For us, this
+// value of n starts at 1000, yielding 10^(2^1002) as "latintenhundredyllion".
+// Although this system hypothetically allows for further recursion as in the
+// string "latinlatinlatinbyllionyllionyllionyllion", we do not support this
+// level of recursion at this time.
+pub fn latin_yllion(n: usize) -> String {
+	let digits = n.to_string();
+	let mut name = String::from("latin");
+	if let Ok(words) = GroupWords::new(digits.bytes(), digits.len(), YllionGroups::default()) {
+		for word in words.flatten() {
+			// Writing to a String cannot fail.
+			let _ = write!(name, "{}", word);
+		}
+	}
+	name
+}
+
+impl fmt::Display for Word {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Word::Small(s)    => f.write_str(s),
+			Word::Hundred     => f.write_str("hundred"),
+			Word::Thousand    => f.write_str("thousand"),
+			Word::Myriad      => f.write_str("myriad"),
+			Word::Zillion(p)  => {
+				write_zillion_prefixes(p, f)?;
+				f.write_str("on")
+			}
+			Word::Zilliard(p) => {
+				write_zillion_prefixes(p, f)?;
+				f.write_str("ard")
+			}
+			Word::Yllion(n)   => {
+				let prefix = if n > 999 { latin_yllion(n) }
+				else { latin_prefix(n).map_err(|_| fmt::Error)? };
+				f.write_str(prefix.as_str())?;
+				f.write_str("yllion")
+			}
+		}
+	}
+}
+
+// Writes the ordinal form of a word, for use as the last word of an ordinal
+// name. Small numbers have a few irregular forms, tens ending in "y" become
+// "-ieth", and everything else simply gains "th".
+pub fn write_ordinal_word<W: fmt::Write>(word: &Word, out: &mut W) -> fmt::Result {
+	match *word {
+		Word::Small(s) => write_ordinal_str(s, out),
+		_ => write!(out, "{}th", word),
+	}
+}
+
+// Writes the ordinal form of a single word given as text, as for
+// write_ordinal_word.
+pub fn write_ordinal_str<W: fmt::Write>(word: &str, out: &mut W) -> fmt::Result {
+	match word {
+		"one"    => out.write_str("first"),
+		"two"    => out.write_str("second"),
+		"three"  => out.write_str("third"),
+		"five"   => out.write_str("fifth"),
+		"eight"  => out.write_str("eighth"),
+		"nine"   => out.write_str("ninth"),
+		"twelve" => out.write_str("twelfth"),
+		s if s.ends_with('y') => {
+			out.write_str(&s[..s.len() - 1])?;
+			out.write_str("ieth")
+		}
+		s => {
+			out.write_str(s)?;
+			out.write_str("th")
+		}
+	}
+}
+
+// Writes the ordinal name of a number, which is its full name with only the
+// last word changed. One word at a time is held back until the next is made.
+pub fn write_ordinal<S, W>(system: &S, digits: &str, out: &mut W) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	let digits = split_unsigned(digits)?;
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+
+	let mut last = None;
+	for word in system.words(digits.bytes(), digits.len())? {
+		if let Some(prev) = last.replace(word?) { write!(out, "{} ", prev)?; }
+	}
+	if let Some(word) = last { write_ordinal_word(&word, out)?; }
+
+	Ok(())
+}
+
+// Writes each of a string of digits as its own word, as in "one four".
+pub fn write_digit_words<W: fmt::Write>(digits: &str, out: &mut W) -> fmt::Result {
+	for (i, b) in digits.bytes().enumerate() {
+		if i > 0 { out.write_char(' ')?; }
+		match (b - b'0') as usize {
+			0 => out.write_str("zero")?,
+			d => out.write_str(NAMES_UPTO_TWENTY[d])?,
+		}
+	}
+	Ok(())
+}
+
+// Writes the name of a number which may have digits after a decimal point,
+// naming those digits as given by the style.
+pub fn write_decimal<S, W>(
+	system: &S,
+	number: &str,
+	style: FractionStyle,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	let (negative, whole, fraction) = split_decimal(number)?;
+	let has_whole = whole.bytes().any(|b| b != b'0');
+	let has_fraction = fraction.bytes().any(|b| b != b'0');
+	if negative && (has_whole || has_fraction) { out.write_str("minus ")?; }
+
+	match style {
+		FractionStyle::Digits => {
+			write_name_from_iter(system, whole.bytes(), whole.len(), out)?;
+			if !fraction.is_empty() {
+				out.write_str(" point ")?;
+				write_digit_words(fraction, out)?;
+			}
+		}
+		FractionStyle::Units => {
+			// A whole part of zero is left out, unless there is nothing else.
+			if has_whole || !has_fraction {
+				write_name_from_iter(system, whole.bytes(), whole.len(), out)?;
+			}
+			if has_fraction {
+				if has_whole { out.write_str(" and ")?; }
+				write_name_from_iter(system, fraction.bytes(), fraction.len(), out)?;
+				out.write_char(' ')?;
+
+				// The denominator is named from the words of its power of ten.
+				let unit = std::iter::once(b'1').chain(std::iter::repeat_n(b'0', fraction.len()));
+				let plural = fraction.trim_start_matches('0') != "1";
+				write_denominator(system.words(unit, fraction.len() + 1)?, plural, out)?;
+			}
+		}
+	}
+
+	Ok(())
+}
+
+// Writes the name for the reciprocal of a power of ten, such as "one
+// millionth". Only the last word is held back while writing.
+pub fn write_reciprocal<S, W>(system: &S, power: BigUint, out: &mut W) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	if power.is_zero() { return Ok(out.write_str("one")?); }
+
+	// Names which begin with "ten", such as "ten thousand", need a count before them.
+	if system.begins_with_ten(&power) { out.write_str("one ")?; }
+
+	let mut held = HoldLastWord::new(out);
+	system.write_power(power, &mut held)?;
+	Ok(held.finish_ordinal()?)
+}
+
+// Writes the name of a number in scientific notation. The number is named
+// in full if it has at most max_digits digits, and otherwise as its mantissa
+// "times" its power of ten.
+pub fn write_scientific<S, W>(
+	system: &S,
+	number: &str,
+	max_digits: usize,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	let (mantissa, negative_exp, exponent) = split_scientific(number)?;
+	match expand_scientific(mantissa, negative_exp, &exponent, max_digits)? {
+		Some(expanded) => write_decimal(system, expanded.as_str(), FractionStyle::Digits, out),
+		None => {
+			write_decimal(system, mantissa, FractionStyle::Digits, out)?;
+			out.write_str(" times ")?;
+			if negative_exp { write_reciprocal(system, exponent, out) }
+			else { system.write_power(exponent, out) }
+		}
+	}
+}
+
+// Writes a short, rounded name for a number, as a count of the largest unit
+// below it. If any digits are lost to rounding, the name begins with "about".
+pub fn write_approximate<S, W>(
+	system: &S,
+	digits: &str,
+	sig_figs: usize,
+	rounding: Rounding,
+	style: MantissaStyle,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	let approx = approximate(digits, sig_figs, rounding, S::UNIT_DIGITS)?;
+	if !approx.exact { out.write_str("about ")?; }
+
+	let count = approx.decimal();
+	match style {
+		MantissaStyle::Digits => out.write_str(count.as_str())?,
+		MantissaStyle::Words  => write_decimal(system, count.as_str(), FractionStyle::Digits, out)?,
+	}
+	system.write_unit(approx.unit, out)
+}
+
+// Writes the name of a power of ten as the denominator of a fraction, such as
+// "thousandths" or "ten thousandth". The words are those of the full name of
+// the power of ten, with any leading "one" left out.
+pub fn write_denominator<I, W>(words: I, plural: bool, out: &mut W) -> Result<(), ParseError>
+where
+	I: Iterator<Item = Result<Word, ParseError>>,
+	W: fmt::Write
+{
+	let mut last = None;
+	let mut first = true;
+	for word in words {
+		if let Some(prev) = last.replace(word?) {
+			if !(first && prev == Word::Small("one")) { write!(out, "{} ", prev)?; }
+			first = false;
+		}
+	}
+	if let Some(word) = last { write_ordinal_word(&word, out)?; }
+	if plural { out.write_char('s')?; }
+	Ok(())
+}
+
+// Helper function for parse_myriad.
+// Reads the name of a number in the range [1,99] from the start of words,
+// giving back the value along with how many words were used. A value of
+// zero (with no words used) means that words does not start with a number.
+fn parse_hundreds(words: &[&str]) -> (usize, usize) {
+	let value_of = |w: Option<&&str>, table: &[&str]| w
+		.and_then(|w| table.iter().skip(1).position(|n| n == w))
+		.map_or(0, |p| p + 1);
+
+	let tens = value_of(words.first(), &TENS_NAMES);
+	if tens == 0 { 
+		let small = value_of(words.first(), &NAMES_UPTO_TWENTY);
+		return (small, usize::from(small > 0));
+	}
+
+	match value_of(words.get(1), &NAMES_UPTO_TWENTY) {
+		units @ 1..=9 => (10*tens + units, 2),
+		_ => (10*tens, 1),
+	}
+}
+
+// The reverse of myriad_words. Reads a name for a number in the range
+// [1, 9999] from the start of words, giving back its value and how many words
+// were consumed. If words does not begin with a number, (0, 0) is returned,
+// while a "hundred" without anything before it is an error.
+pub fn parse_myriad(words: &[&str]) -> Result<(usize, usize), ParseError> {
+	if words.first() == Some(&"hundred") { 
+		return Err(ParseError::InvalidName);
+	}
+
+	let (mut value, mut used) = parse_hundreds(words);
+	if value > 0 && words.get(used) == Some(&"hundred") {
+		let (rest, rest_used) = parse_hundreds(&words[used+1..]);
+		value = value * 100 + rest;
+		used += rest_used + 1;
+	}
+
+	Ok((value, used))
+}
+
+// The reverse of latin_prefix. Builds a table which gives back the value in
+// the range [0, 999] for each of the latin prefixes.
+pub fn latin_values() -> HashMap<String, usize> {
+	(0..1000)
+		.filter_map(|n| latin_prefix(n).ok().map(|p| (p, n)))
+		.collect()
+}
+
+// Breaks up a string of number words written without spaces between them,
+// such as "tenhundred", back into its words. Besides the words used by
+// myriad_words, any word accepted by the is_word function may be used.
+// Gives back None if there is no way to break up the string into words.
+pub fn split_joined_words<'a>(
+	joined: &'a str,
+	is_word: &dyn Fn(&str) -> bool
+) -> Option<Vec<&'a str>> {
+	if joined.is_empty() { return Some(Vec::new()); }
+
+	// Try the longest words first, backing up if the rest of the string
+	// cannot be broken into words afterwards.
+	(1..=joined.len()).rev()
+		.filter_map(|end| joined.get(..end).zip(joined.get(end..)))
+		.filter(|(word, _)| {
+			*word == "hundred"
+				|| NAMES_UPTO_TWENTY[1..].contains(word)
+				|| TENS_NAMES[2..].contains(word)
+				|| is_word(word)
+		})
+		.find_map(|(word, rest)| {
+			split_joined_words(rest, is_word).map(|mut words| {
+				words.insert(0, word);
+				words
+			})
+		})
+}
+
+// Changes the case of each letter written to it before passing it on to
+// another writer. A word is taken to begin after a space or a hyphen, so the
+// pieces of a chained zillion, which are written one at a time, are still
+// treated as a single word.
+pub struct CaseWriter<'a, W> {
+	inner: &'a mut W,
+	case: Case,
+	started: bool,
+	word_start: bool,
+}
+
+impl<'a, W: fmt::Write> CaseWriter<'a, W> {
+	pub fn new(inner: &'a mut W, case: Case) -> Self {
+		CaseWriter { inner, case, started: false, word_start: true }
+	}
+
+	// Writes a minor word such as "and", which is not given a capital in
+	// title case.
+	pub fn write_minor(&mut self, s: &str) -> fmt::Result {
+		for c in s.chars() {
+			let capital = self.case == Case::Upper;
+			self.inner.write_char(if capital { c.to_ascii_uppercase() } else { c })?;
+			self.started |= c.is_alphabetic();
+			self.word_start = c == ' ' || c == '-';
+		}
+		Ok(())
+	}
+}
+
+impl<W: fmt::Write> fmt::Write for CaseWriter<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		if self.case == Case::Lower { return self.inner.write_str(s); }
+
+		for c in s.chars() {
+			let capital = match self.case {
+				Case::Upper    => true,
+				Case::Title    => self.word_start,
+				Case::Sentence => !self.started,
+				Case::Lower    => false,
+			};
+			self.inner.write_char(if capital { c.to_ascii_uppercase() } else { c })?;
+			self.started |= c.is_alphabetic();
+			self.word_start = c == ' ' || c == '-';
+		}
+		Ok(())
+	}
+}
+
+// Lets a source of words be looked ahead of by more than one word, so that a
+// group of a name can be seen in full before its first word is written.
+struct Lookahead<I> {
+	source: I,
+	ahead: VecDeque<Word>,
+}
+
+impl<I: Iterator<Item = Result<Word, ParseError>>> Lookahead<I> {
+	fn next(&mut self) -> Result<Option<Word>, ParseError> {
+		match self.ahead.pop_front() {
+			Some(word) => Ok(Some(word)),
+			None => self.source.next().transpose(),
+		}
+	}
+
+	// Gives the word n places after the next one, without taking it.
+	fn peek(&mut self, n: usize) -> Result<Option<Word>, ParseError> {
+		while self.ahead.len() <= n {
+			match self.source.next().transpose()? {
+				Some(word) => self.ahead.push_back(word),
+				None => return Ok(None),
+			}
+		}
+		Ok(Some(self.ahead[n]))
+	}
+
+	// Whether the rest of the name is made only of small numbers, so that the
+	// group just begun is the last one and has no "hundred" in it, such as
+	// the "forty two" at the end of "one million forty two".
+	fn last_group_below_hundred(&mut self) -> Result<bool, ParseError> {
+		let mut n = 0;
+		loop {
+			match self.peek(n)? {
+				None => return Ok(true),
+				Some(Word::Small(_)) => n += 1,
+				Some(_) => return Ok(false),
+			}
+		}
+	}
+}
+
+// Writes the words of a full name, joining them as given by a style. Groups
+// are found by where a word such as "million" or "myriad" is followed by a
+// number, so this works the same way for every naming system.
+pub fn write_styled_words<I, W>(
+	words: I,
+	style: &Style,
+	out: &mut CaseWriter<W>
+) -> Result<(), ParseError>
+where
+	I: Iterator<Item = Result<Word, ParseError>>,
+	W: fmt::Write
+{
+	let mut words = Lookahead { source: words, ahead: VecDeque::new() };
+	let mut prev: Option<Word> = None;
+
+	while let Some(word) = words.next()? {
+		let joiner = match (prev, word) {
+			(None, _) => "",
+			(Some(Word::Small(t)), Word::Small(_)) if style.hyphenate && t.ends_with("ty") => "-",
+			(Some(Word::Hundred), Word::Small(_)) if style.and => " and ",
+			(Some(p), Word::Small(_)) if !matches!(p, Word::Small(_) | Word::Hundred) => {
+				// A number after a word such as "million" begins a new group.
+				let last = style.and && words.last_group_below_hundred()?;
+				match (last, style.separator) {
+					(true, _) => " and ",
+					(false, Separator::Space) => " ",
+					(false, Separator::Comma) => ", ",
+				}
+			}
+			_ => " ",
+		};
+
+		if joiner == " and " { out.write_minor(joiner)?; }
+		else { out.write_str(joiner)?; }
+		write!(out, "{}", word)?;
+		prev = Some(word);
+	}
+
+	Ok(())
+}
+
+// Writes the full name of a number that may have a sign, joining its words
+// as given by a style. The digits are checked before anything is written.
+pub fn write_signed_name<S, W>(
+	system: &S,
+	digits: &str,
+	negative: &str,
+	style: &Style,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	S: NameParts,
+	W: fmt::Write
+{
+	let (is_negative, digits) = split_sign(digits);
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	if digits.is_empty() { return Err(ParseError::Empty); }
+
+	// There is no such thing as negative zero, so it is named as plain zero.
+	let mut out = CaseWriter::new(out, style.case);
+	if is_negative && digits.bytes().any(|b| b != b'0') {
+		out.write_str(negative)?;
+		out.write_char(' ')?;
+	}
+	let words = system.words(digits.bytes(), digits.len())?;
+	write_styled_words(words, style, &mut out)
+}
+
+// Passes everything written to it on to another writer, except for the last
+// word, which is held back so that it can be changed once writing is done.
+pub struct HoldLastWord<'a, W> {
+	inner: &'a mut W,
+	word: String,
+}
+
+impl<'a, W: fmt::Write> HoldLastWord<'a, W> {
+	pub fn new(inner: &'a mut W) -> Self {
+		HoldLastWord { inner, word: String::new() }
+	}
+
+	// Writes out the held word in its ordinal form.
+	pub fn finish_ordinal(self) -> fmt::Result {
+		write_ordinal_str(self.word.as_str(), self.inner)
+	}
+}
+
+impl<W: fmt::Write> fmt::Write for HoldLastWord<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		match s.rfind(' ') {
+			Some(i) => {
+				self.inner.write_str(self.word.as_str())?;
+				self.inner.write_str(&s[..=i])?;
+				self.word.clear();
+				self.word.push_str(&s[i+1..]);
+			}
+			None => self.word.push_str(s),
+		}
+		Ok(())
+	}
+}
+
+// Allows an io::Write to be used by the functions which write names into a
+// fmt::Write. The first io::Error is held onto, as fmt::Error cannot carry it.
+pub struct IoWriter<'a, W> {
+	inner: &'a mut W,
+	error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.inner.write_all(s.as_bytes()).map_err(|e| {
+			self.error = Some(e);
+			fmt::Error
+		})
+	}
+}
+
+// Runs a function which writes a name into a fmt::Write on an io::Write
+// instead. Errors from the io::Write are given back as they were, while any
+// other error is wrapped up in an io::Error.
+pub fn write_io<W, F>(out: &mut W, write: F) -> io::Result<()>
+where
+	W: io::Write,
+	F: FnOnce(&mut IoWriter<W>) -> Result<(), ParseError>
+{
+	let mut writer = IoWriter { inner: out, error: None };
+	write(&mut writer).map_err(|e| writer.error
+		.take()
+		.unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, e)))
+}
+
+// Gives the name sent to a writer by one of the write functions as a String.
+pub fn name_string<F>(write: F) -> Result<String, ParseError>
+where
+	F: FnOnce(&mut String) -> Result<(), ParseError>
+{
+	let mut name = String::new();
+	write(&mut name)?;
+	Ok(name)
+}
+
+// Counts the digits in a reader from its current position up to the end,
+// and then seeks back to where it started. Any character other than 0-9
+// found along the way is an error.
+fn count_digits<R: io::Read + io::Seek>(reader: &mut R) -> io::Result<usize> {
+	let start = reader.stream_position()?;
+	let mut buffer = [0u8; 8192];
+	let mut count: usize = 0;
+
+	loop {
+		let n = match reader.read(&mut buffer) {
+			Ok(0) => break,
+			Ok(n) => n,
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(e) => return Err(e),
+		};
+
+		if !buffer[..n].iter().all(u8::is_ascii_digit) {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, ParseError::InvalidDigit));
+		}
+		count += n;
+	}
+
+	reader.seek(io::SeekFrom::Start(start))?;
+	Ok(count)
+}
+
+// Writes the full name of a number whose digits are read from a seekable
+// source, to an io::Write. The digits are read twice: once to count and check
+// them, and then again as they are named.
+pub fn write_name_from_reader<S, R, W>(system: &S, reader: &mut R, out: &mut W) -> io::Result<()>
+where
+	S: NameParts,
+	R: io::Read + io::Seek,
+	W: io::Write
+{
+	let len = count_digits(reader)?;
+	let mut digits = ReadDigits::new(reader);
+	let result = write_io(out, |w| write_name_from_iter(system, &mut digits, len, w));
+	digits.error.map_or(result, Err)
+}
+
+// Allows the bytes of a reader to be used as a source of digits. Reading
+// stops at the first io::Error, which is held onto so that it can be given
+// back to the caller.
+struct ReadDigits<R> {
+	bytes: io::Bytes<io::BufReader<R>>,
+	error: Option<io::Error>,
+}
+
+impl<R: io::Read> ReadDigits<R> {
+	fn new(reader: R) -> Self {
+		ReadDigits { bytes: io::Read::bytes(io::BufReader::new(reader)), error: None }
+	}
+}
+
+impl<R: io::Read> Iterator for ReadDigits<R> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		match self.bytes.next()? {
+			Ok(b) => Some(b),
+			Err(e) => {
+				self.error = Some(e);
+				None
+			}
+		}
+	}
+}
 
-	output.pop(); // Remove the space at the end
-	Ok(output)
+// Builds a number of len digits for tests which read names back in, out of a
+// simple pseudorandom sequence, with some zeroes to leave groups empty.
+#[cfg(test)]
+pub fn random_digits(len: usize, seed: &mut u64) -> String {
+	(0..len).map(|i| {
+		*seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+		let d = (*seed >> 60) as u8 % 10;
+		if i > 0 && *seed >> 62 == 0 { '0' } else { (b'1' + d % 9) as char }
+	}).collect()
 }
@@ -10,24 +10,53 @@
 //! used in the UK before switching to the short scale, where instead of using
 //! milliard to refer to the value 10^9, the term "one thousand million" is used
 //! instead.
+//!
+//! Most functions which give a name as a `String` also have a `write_` form,
+//! which takes the same arguments along with a writer `out`, and sends the
+//! name to it as it is made. Unless noted otherwise, the input is checked
+//! before anything is written, and if `out` returns an error, the function
+//! stops and returns `ParseError::WriteFailed`.
 
 extern crate num_traits;
 extern crate num_bigint;
 
-use std::str::FromStr;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::str::Bytes;
 use num_traits::cast::ToPrimitive;
 use num_traits::identities::Zero;
 use num_traits::identities::One;
 use num_bigint::BigUint;
 
 use crate::common::{
+	GroupWords,
+	Groups,
+	MAX_PARSED_DIGITS,
+	NameParts,
+	approximate,
 	is_all_digits,
-	num_from_slice,
 	latin_prefix,
-	myriad_number
+	latin_values,
+	myriad_words,
+	name_string,
+	num_from_slice,
+	parse_myriad,
+	power_from_digits,
+	split_unsigned,
+	write_approximate,
+	write_decimal,
+	write_io,
+	write_name_from_iter,
+	write_name_from_reader,
+	write_ordinal,
+	write_reciprocal,
+	write_scientific,
+	write_signed_name,
+	write_zillion_abbreviations
 };
 
-use crate::ParseError;
+use crate::{FractionStyle, MantissaStyle, NamingSystem, ParseError, Rounding, Style, Word};
 
 /// A parameter for Conway-Wechsler functions which indicates how number names
 /// change every power of 1000.
@@ -38,12 +67,20 @@ pub enum Scale {
 	Short,
 	/// The scale formerly used in the UK, and based on Chuquet's
 	/// scheme for naming numbers. 10^9 will be called one thousand million.
+	/// Chuquet's own names are given by the `historical` module.
 	LongBritish,
 	/// The scale currently in use by many European languages, most
 	/// notably French. 10^9 will be called one milliard.
 	LongPeletier,
 }
 
+/// The Conway-Wechsler system as a `NamingSystem`, using the given scale for
+/// each of its names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConwayWechsler {
+	pub scale: Scale,
+}
+
 
 // Create a name for a single 3 digit zillion number, ending in -illi.
 // Value for zero is "nilli", for use in chained zillion numbers.
@@ -54,45 +91,87 @@ fn zillion_prefix(num: usize) -> Result<String, ParseError> {
 	Ok(name)
 }
 
-// Create a name for an arbitrary power of 1000.
-// Value for zero is the empty string.
+// Create the words for an arbitrary power of 1000.
+// Value for zero has no words.
 // Value for one is "thousand".
-// Value for anything greater will involve repeated application of the
-// zillion_prefix function, to create a number ending in "illion",
-// or "ard" depending on whether or not we are using the long scale.
-fn zillion_number(num: usize, scale: Scale) -> Result<String, ParseError> {
-	if num == 0 { return Ok(String::from("")); }
-	if num == 1 { return Ok(String::from("thousand")); }
-
-	// Create adjustments to name for long scale.
-	let (prefix, suffix) = match (scale, num % 2) {
-		(Scale::LongBritish, 1)  => ("thousand ", "on"),
-		(Scale::LongPeletier, 1) => ("", "ard"),
-		(_, _) => ("", "on"),
-	};
+// Value for anything greater will be a single zillion, whose latin prefixes
+// end in "illion", or "illiard" depending on whether or not we are using the
+// long scale. The British long scale may also place "thousand" before it.
+fn zillion_words(num: usize, scale: Scale) -> Vec<Word> {
+	if num == 0 { return Vec::new(); }
+	if num == 1 { return vec![Word::Thousand]; }
 
-	let mut power = match scale {
+	let power = match scale {
 		Scale::Short => num - 1,
 		_ => ((num + 2) / 2) - 1,
 	};
 
-	let mut name = String::from(prefix);
+	// Create adjustments to name for long scale.
+	match (scale, num % 2) {
+		(Scale::LongBritish, 1)  => vec![Word::Thousand, Word::Zillion(power)],
+		(Scale::LongPeletier, 1) => vec![Word::Zilliard(power)],
+		(_, _) => vec![Word::Zillion(power)],
+	}
+}
 
-	// Zillion prefixes added in reverse order here.
-	// e.g. in millinillion, first add "nilli", then "milli", then "on".
-	let mut zillions = Vec::with_capacity(7);
-	while power > 0 {
-		let zillion = zillion_prefix(power % 1000)?;
-		zillions.push(zillion);
-		power /= 1000;
+// The groups of a full name are three digits each, and are followed by the
+// words for their power of 1000.
+impl Groups for Scale {
+	const SIZE: usize = 3;
+
+	fn group_words(
+		&mut self,
+		num: usize,
+		remaining: usize,
+		words: &mut VecDeque<Word>
+	) -> Result<(), ParseError> {
+		if num > 0 {
+			words.extend(myriad_words(num)?);
+			words.extend(zillion_words(remaining / 3, *self));
+		}
+		Ok(())
 	}
+}
+
+impl NameParts for Scale {
+	type Groups = Scale;
 
-	for z in zillions.iter().rev() {
-		name.push_str(z.as_str());
+	const UNIT_DIGITS: usize = 3;
+
+	fn groups(&self) -> Scale {
+		*self
 	}
 
-	name.push_str(suffix);
-	Ok(name)
+	fn write_power<W: fmt::Write>(&self, power: BigUint, out: &mut W) -> Result<(), ParseError> {
+		write_power(power, *self, out)
+	}
+
+	fn begins_with_ten(&self, power: &BigUint) -> bool {
+		(power % 3u32).is_one()
+	}
+
+	// The unit is named just as it would be in full_name.
+	fn write_unit<W: fmt::Write>(&self, unit: usize, out: &mut W) -> Result<(), ParseError> {
+		for word in zillion_words(unit, *self) {
+			write!(out, " {}", word)?;
+		}
+		Ok(())
+	}
+}
+
+/// An iterator over each word in the full name of a number, as given by
+/// the `words` function.
+pub struct Words<'a> {
+	inner: GroupWords<Bytes<'a>, Scale>,
+}
+
+impl Iterator for Words<'_> {
+	type Item = Word;
+
+	// The digits have already been checked, so the words cannot fail.
+	fn next(&mut self) -> Option<Word> {
+		self.inner.next().and_then(Result::ok)
+	}
 }
 
 /// Gives a full length name for a number represented by an arbitrary sequence
@@ -101,11 +180,12 @@ fn zillion_number(num: usize, scale: Scale) -> Result<String, ParseError> {
 /// # Arguments
 /// 
 /// * `digits` - A string slice that holds a representation of the number
-/// using only the digits 0-9. If any other character is present, this function
-/// will return an Err.
+///   using only the digits 0-9, optionally preceded by a sign. A negative
+///   number is prefixed with "minus". If any other character is present, this
+///   function will return an Err.
 /// * `scale` - An enumerated value to determine which scale should
-/// be used. Short scales use a new "-illion" name for every power of 1000,
-/// while long scales use a new "-illion" name for every power of 1000000.
+///   be used. Short scales use a new "-illion" name for every power of 1000,
+///   while long scales use a new "-illion" name for every power of 1000000.
 /// 
 /// # Example
 /// 
@@ -117,66 +197,370 @@ fn zillion_number(num: usize, scale: Scale) -> Result<String, ParseError> {
 /// assert_eq!("nineteen billion forty two", billion.as_str());
 /// ```
 pub fn full_name(digits: &str, scale: Scale) -> Result<String, ParseError> {
-	// Sanity checks. We want the string to be entirely digits, and we want
-	// to handle the case of leading zeroes. If all digits are zero, we want
-	// to just return the string "zero", and otherwise process from the
-	// first nonzero character.
-	let first_nonzero = is_all_digits(digits)
-		.then(|| digits)
-		.ok_or(ParseError::InvalidDigit)
-		.and_then(|d|
-			if d.is_empty() { Err(ParseError::Empty) }
-			else { Ok(d.find(|c| c != '0')) }
-		)?;
-
-	let (mut i, mut output) = first_nonzero.map_or_else(
-		|| (0, String::from("zero")),
-		|idx| (idx, String::from(""))
-	);
-
-	if !output.is_empty() { return Ok(output); }
-
-	// Determine how many digits to process, and how many digits are in the
-	// first zillion (e.g. 2 in the case of 12 tredecillion).
-	let mut remaining = digits.len() - i;
-	let first = remaining % 3;
-
-	if first > 0 {
-		let num     = num_from_slice(digits, i, first);
-		let leading = myriad_number(num)?;
-		let zillion = zillion_number(remaining / 3, scale)?;
-
-		output.push_str(leading.as_str());
-		if !zillion.is_empty() {
-			output.push(' ');
-			output.push_str(zillion.as_str());
-		}
+	name_string(|out| write_full_name(digits, scale, out))
+}
 
-		remaining -= first;
-		i += first;
-	}
+/// Gives the full length name of a number that is already held as an integer,
+/// such as a `u64`, a `u128` or a `BigUint`. The number is written out in
+/// base 10 and named in the same manner as `write_full_name_from_iter`, so its
+/// digits are still read one at a time, but can never be rejected.
+///
+/// # Arguments
+/// 
+/// * `num` - The number to name. Anything that converts into a `BigUint` may
+///   be given.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, full_name_of};
+/// let billion = full_name_of(1_000_000_042u64, Scale::Short).unwrap();
+/// assert_eq!("one billion forty two", billion.as_str());
+/// ```
+pub fn full_name_of<T: Into<BigUint>>(num: T, scale: Scale) -> Result<String, ParseError> {
+	let digits = num.into().to_str_radix(10);
+	name_string(|out| write_full_name_from_iter(digits.bytes(), digits.len(), scale, out))
+}
 
-	// Handle the rest of the digits in chunks of three at a time.
-	while remaining > 0 {
-		let num     = num_from_slice(digits, i, 3);
-		let leading = myriad_number(num)?;
-		let zillion = zillion_number(remaining / 3 - 1, scale)?;
+/// Gives each word in the full length name of a number in turn, so that the
+/// name may be styled or inspected without reading the text of `full_name`
+/// back in. Each word is only made once the words before it have been taken.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::Word;
+/// use googology::conway_wechsler::{Scale, words};
+/// let words = words("42000000000", Scale::LongBritish)
+///     .unwrap()
+///     .collect::<Vec<Word>>();
+/// let expected = vec![
+///     Word::Small("forty"),
+///     Word::Small("two"),
+///     Word::Thousand,
+///     Word::Zillion(1),
+/// ];
+/// assert_eq!(expected, words);
+/// ```
+pub fn words(digits: &str, scale: Scale) -> Result<Words<'_>, ParseError> {
+	let digits = split_unsigned(digits)?;
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	let inner = scale.words(digits.bytes(), digits.len())?;
+	Ok(Words { inner })
+}
 
-		if !leading.is_empty() {
-			if !output.is_empty() { output.push(' '); }
+/// Writes a full length name for a number represented by an arbitrary sequence
+/// of digits, in the same manner as `full_name`. Each word is sent to the
+/// writer as soon as it is made, rather than building the whole name in memory.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, write_full_name};
+/// let mut billion = String::from("exactly ");
+/// write_full_name("1000000000", Scale::Short, &mut billion).unwrap();
+/// assert_eq!("exactly one billion", billion.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_signed_full_name(digits, scale, "minus", out)
+}
 
-			output.push_str(leading.as_str());
-			if !zillion.is_empty() {
-				output.push(' ');
-				output.push_str(zillion.as_str());
-			}
-		}
+/// Gives the full length name of a number that may be negative, in the same
+/// manner as `full_name`, but with a choice of word for the sign.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `negative` - The word to put before the name of a negative number, such
+///   as "minus" or "negative". Zero is never given this word.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, signed_full_name};
+/// let name = signed_full_name("-42000000000", Scale::Short, "negative").unwrap();
+/// assert_eq!("negative forty two billion", name.as_str());
+/// ```
+pub fn signed_full_name(
+	digits: &str,
+	scale: Scale,
+	negative: &str
+) -> Result<String, ParseError> {
+	name_string(|out| write_signed_full_name(digits, scale, negative, out))
+}
 
-		i += 3;
-		remaining -= 3;
-	}
+/// Writes the full length name of a number that may be negative, in the same
+/// manner as `signed_full_name`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, write_signed_full_name};
+/// let mut name = String::new();
+/// write_signed_full_name("-7", Scale::Short, "minus", &mut name).unwrap();
+/// assert_eq!("minus seven", name.as_str());
+/// ```
+pub fn write_signed_full_name<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	negative: &str,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_signed_name(&scale, digits, negative, &Style::default(), out)
+}
 
-	Ok(output)
+/// Gives the full length name of a number in the same manner as `full_name`,
+/// but with its words joined as given by a style. The default style gives the
+/// same name as `full_name`.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `style` - Options for hyphens, "and", and the separator between groups.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::{Separator, Style};
+/// use googology::conway_wechsler::{Scale, full_name_with};
+/// let style = Style { hyphenate: true, and: true, ..Style::default() };
+/// let name = full_name_with("101000021", Scale::Short, &style).unwrap();
+/// assert_eq!("one hundred and one million and twenty-one", name.as_str());
+/// ```
+pub fn full_name_with(
+	digits: &str,
+	scale: Scale,
+	style: &Style
+) -> Result<String, ParseError> {
+	name_string(|out| write_full_name_with(digits, scale, style, out))
+}
+
+/// Writes the full length name of a number in the same manner as
+/// `full_name_with`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::{Separator, Style};
+/// use googology::conway_wechsler::{Scale, write_full_name_with};
+/// let style = Style { separator: Separator::Comma, ..Style::default() };
+/// let mut name = String::new();
+/// write_full_name_with("7000000042", Scale::LongPeletier, &style, &mut name).unwrap();
+/// assert_eq!("seven milliard, forty two", name.as_str());
+/// ```
+pub fn write_full_name_with<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	style: &Style,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_signed_name(&scale, digits, "minus", style, out)
+}
+
+/// Writes a full length name for a number whose digits come from an iterator,
+/// in the same manner as `write_full_name`. This allows for a number to be
+/// named without ever holding all of its digits in memory at once.
+///
+/// # Arguments
+/// 
+/// * `digits` - An iterator over the ASCII digits of the number, from most
+///   to least significant. As these are only checked as they are read, if any
+///   other character is found, this function may return an Err after some
+///   of the name has already been written.
+/// * `len` - How many digits the iterator will give. This is needed to know
+///   which zillion the first digits belong to. If the iterator gives any more
+///   or any less digits, `ParseError::LengthMismatch` is returned.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, write_full_name_from_iter};
+/// let digits = std::iter::once(b'7').chain(std::iter::repeat(b'0').take(9));
+/// let mut name = String::new();
+/// write_full_name_from_iter(digits, 10, Scale::Short, &mut name).unwrap();
+/// assert_eq!("seven billion", name.as_str());
+/// ```
+pub fn write_full_name_from_iter<I, W>(
+	digits: I,
+	len: usize,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	I: IntoIterator<Item = u8>,
+	W: fmt::Write
+{
+	write_name_from_iter(&scale, digits.into_iter(), len, out)
+}
+
+/// Writes a full length name for a number whose digits are read from a
+/// seekable source such as a file, to an `io::Write`. The digits are read
+/// twice: once to count and check them, and then again as they are named,
+/// so the number never needs to be held in memory all at once.
+///
+/// Reading begins at the current position of the reader, and continues up to
+/// the end, so any trailing newline must not be present. If the digits cannot
+/// be given a name, the `ParseError` is returned inside an `io::Error` of the
+/// kind `io::ErrorKind::InvalidInput`. 
+/// 
+/// # Example
+/// 
+/// ```
+/// use std::io::Cursor;
+/// use googology::conway_wechsler::{Scale, write_full_name_from_reader};
+/// let mut digits = Cursor::new("4200000");
+/// let mut bytes = Vec::new();
+/// write_full_name_from_reader(&mut digits, Scale::Short, &mut bytes).unwrap();
+/// assert_eq!(b"four million two hundred thousand", bytes.as_slice());
+/// ```
+pub fn write_full_name_from_reader<R, W>(
+	reader: &mut R,
+	scale: Scale,
+	out: &mut W
+) -> io::Result<()>
+where
+	R: io::Read + io::Seek,
+	W: io::Write
+{
+	write_name_from_reader(&scale, reader, out)
+}
+
+/// Writes a full length name for a number in the same manner as
+/// `write_full_name`, but to an `io::Write` such as a file. As each word is
+/// written separately, an unbuffered writer should be wrapped in an
+/// `io::BufWriter` first.
+///
+/// If the digits cannot be given a name, the `ParseError` is returned inside
+/// an `io::Error` of the kind `io::ErrorKind::InvalidInput`. 
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, write_full_name_io};
+/// let mut bytes = Vec::new();
+/// write_full_name_io("1000000000", Scale::LongPeletier, &mut bytes).unwrap();
+/// assert_eq!(b"one milliard", bytes.as_slice());
+/// ```
+pub fn write_full_name_io<W: io::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> io::Result<()> {
+	write_io(out, |w| write_full_name(digits, scale, w))
+}
+
+/// Gives the ordinal name of a number, such as "twelfth" or "one millionth".
+/// This is the same as `full_name`, with only the last word changed.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, ordinal_name};
+/// let milliardth = ordinal_name("1000000000", Scale::LongPeletier).unwrap();
+/// let twenty_first = ordinal_name("21", Scale::Short).unwrap();
+/// assert_eq!("one milliardth", milliardth.as_str());
+/// assert_eq!("twenty first", twenty_first.as_str());
+/// ```
+pub fn ordinal_name(digits: &str, scale: Scale) -> Result<String, ParseError> {
+	name_string(|out| write_ordinal_name(digits, scale, out))
+}
+
+/// Writes the ordinal name of a number, in the same manner as `ordinal_name`.
+/// Every word but the last is sent to the writer as soon as it is made.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, write_ordinal_name};
+/// let mut name = String::new();
+/// write_ordinal_name("1000042", Scale::Short, &mut name).unwrap();
+/// assert_eq!("one million forty second", name.as_str());
+/// ```
+pub fn write_ordinal_name<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_ordinal(&scale, digits, out)
+}
+
+/// Gives a name for a number which may have digits after a decimal point, such
+/// as "12.5" or "0.000001". A negative number is prefixed with "minus".
+///
+/// # Arguments
+/// 
+/// * `number` - A string slice that holds a representation of the number
+///   using only the digits 0-9, with an optional sign and decimal point. If
+///   any other character is present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `style` - Whether the digits after the decimal point are named one by
+///   one, or as a fraction.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::FractionStyle;
+/// use googology::conway_wechsler::{Scale, decimal_name};
+/// let pi = decimal_name("3.14", Scale::Short, FractionStyle::Digits).unwrap();
+/// let small = decimal_name("0.000325", Scale::Short, FractionStyle::Units).unwrap();
+/// assert_eq!("three point one four", pi.as_str());
+/// assert_eq!("three hundred twenty five millionths", small.as_str());
+/// ```
+pub fn decimal_name(
+	number: &str,
+	scale: Scale,
+	style: FractionStyle
+) -> Result<String, ParseError> {
+	name_string(|out| write_decimal_name(number, scale, style, out))
+}
+
+/// Writes a name for a number which may have digits after a decimal point, in
+/// the same manner as `decimal_name`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::FractionStyle;
+/// use googology::conway_wechsler::{Scale, write_decimal_name};
+/// let mut name = String::new();
+/// write_decimal_name("12.5", Scale::Short, FractionStyle::Units, &mut name).unwrap();
+/// assert_eq!("twelve and five tenths", name.as_str());
+/// ```
+pub fn write_decimal_name<W: fmt::Write>(
+	number: &str,
+	scale: Scale,
+	style: FractionStyle,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_decimal(&scale, number, style, out)
 }
 
 /// Gives a name for a number representing a power of ten.
@@ -186,11 +570,12 @@ pub fn full_name(digits: &str, scale: Scale) -> Result<String, ParseError> {
 /// # Arguments
 /// 
 /// * `digits` - A string slice that holds a representation of the number
-/// using only the digits 0-9. If any other character is present, this function
-/// will return an Err.
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. A negative
+///   exponent gives `ParseError::NegativeExponent`.
 /// * `scale` - An enumerated value to determine which scale should
-/// be used. Short scales use a new "-illion" name for every power of 1000,
-/// while long scales use a new "-illion" name for every power of 1000000.
+///   be used. Short scales use a new "-illion" name for every power of 1000,
+///   while long scales use a new "-illion" name for every power of 1000000.
 /// 
 /// # Example
 ///
@@ -202,19 +587,56 @@ pub fn full_name(digits: &str, scale: Scale) -> Result<String, ParseError> {
 /// assert_eq!("one billion", billion.as_str());
 /// ```
 pub fn power_of_ten(digits: &str, scale: Scale) -> Result<String, ParseError> {
-	// Sanity check. We want to convert our input string into a Bignum.
-	// The num_bigint crate doesn't quite allow us to know the cause of
-	// error, but from what we can tell, it's either an invalid digit or
-	// an empty string. So we'll make this clear in our own error.
-	let mut power = is_all_digits(digits)
-		.then(|| digits)
-		.ok_or(ParseError::InvalidDigit)
-		.and_then(|d| 
-			if d.is_empty() { Err(ParseError::Empty) }
-			else { Ok(d) }
-		)
-		.and_then(|d| BigUint::from_str(d).map_err(|_| ParseError::InternalError))?;
+	name_string(|out| write_power_of_ten(digits, scale, out))
+}
+
+/// Gives a name for a power of ten whose exponent is already held as a
+/// `BigUint`, skipping the checks made by `power_of_ten`.
+///
+/// # Arguments
+/// 
+/// * `power` - The power of ten to name.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::BigUint;
+/// use googology::conway_wechsler::{Scale, power_of_ten_of};
+/// let name = power_of_ten_of(&BigUint::from(3003u32), Scale::Short).unwrap();
+/// assert_eq!("one millinillion", name.as_str());
+/// ```
+pub fn power_of_ten_of(power: &BigUint, scale: Scale) -> Result<String, ParseError> {
+	name_string(|out| write_power(power.clone(), scale, out))
+}
+
+/// Writes a name for a number representing a power of ten, in the same manner
+/// as `power_of_ten`. Each zillion prefix is sent to the writer as soon as it
+/// is made, rather than building the whole name in memory.
+///
+/// # Example
+///
+/// ```
+/// use googology::conway_wechsler::{Scale, write_power_of_ten};
+/// let mut googol = String::new();
+/// write_power_of_ten("100", Scale::Short, &mut googol).unwrap();
+/// assert_eq!("ten duotrigintillion", googol.as_str());
+/// ```
+pub fn write_power_of_ten<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError> {
+	let power = power_from_digits(digits)?;
+	write_power(power, scale, out)
+}
 
+// Writes the name for a power of ten, once its digits have been read.
+fn write_power<W: fmt::Write>(
+	mut power: BigUint,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError> {
 	// Get the leading word (e.g. "ten" in "ten million")
 	let s = (&power % 3u32)
 		.to_u32()
@@ -226,20 +648,17 @@ pub fn power_of_ten(digits: &str, scale: Scale) -> Result<String, ParseError> {
 		})
 		.unwrap_or("");
 
-	let mut output = String::from(s);
+	out.write_str(s)?;
 
 	// Convert into power of one thousand
 	// We may return early for edge cases.
 	power /= 3u32;
-	if power.is_zero() { return Ok(output); }
-	if power.is_one() {
-		output.push_str(" thousand");
-		return Ok(output);
-	}
+	if power.is_zero() { return Ok(()); }
+	if power.is_one() { return Ok(out.write_str(" thousand")?); }
 
 	// Compute zillion number.
 	power -= 1u32;
-	output.push_str(" ");
+	out.write_char(' ')?;
 
 	// Adjust for long scale if necessary
 	let (prefix, suffix) = match (scale, (&power % 2u32).is_zero()) {
@@ -254,30 +673,491 @@ pub fn power_of_ten(digits: &str, scale: Scale) -> Result<String, ParseError> {
 		power -= 1u32;
 	}
 
-	output.push_str(prefix);
+	out.write_str(prefix)?;
+
+	// Add zillions starting from the most significant. Each group of three
+	// decimal digits in the power gives one of the zillion prefixes, so the
+	// first group may be shorter than the rest.
+	let power = power.to_str_radix(10);
+	let mut i = 0;
+	let mut size = match power.len() % 3 { 0 => 3, n => n };
+	while i < power.len() {
+		let zillion = zillion_prefix(num_from_slice(power.as_str(), i, size))?;
+		out.write_str(zillion.as_str())?;
+		i += size;
+		size = 3;
+	}
+
+	Ok(out.write_str(suffix)?)
+}
+
+/// Writes a name for a number representing a power of ten in the same manner
+/// as `write_power_of_ten`, but to an `io::Write` such as a file.
+///
+/// If the digits cannot be given a name, the `ParseError` is returned inside
+/// an `io::Error` of the kind `io::ErrorKind::InvalidInput`. 
+pub fn write_power_of_ten_io<W: io::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> io::Result<()> {
+	write_io(out, |w| write_power_of_ten(digits, scale, w))
+}
+
+/// Gives a name for the reciprocal of a power of ten, such as "one millionth"
+/// for 10^-6. The exponent is given without its minus sign, and may be just
+/// as large as those given to `power_of_ten`.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9, without a sign. If any other character is
+///   present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::conway_wechsler::{Scale, reciprocal_power_of_ten};
+/// let trillionth = reciprocal_power_of_ten("12", Scale::Short).unwrap();
+/// let sextillionth = reciprocal_power_of_ten("22", Scale::Short).unwrap();
+/// assert_eq!("one trillionth", trillionth.as_str());
+/// assert_eq!("one ten sextillionth", sextillionth.as_str());
+/// ```
+pub fn reciprocal_power_of_ten(digits: &str, scale: Scale) -> Result<String, ParseError> {
+	name_string(|out| write_reciprocal_power_of_ten(digits, scale, out))
+}
+
+/// Writes a name for the reciprocal of a power of ten, in the same manner as
+/// `reciprocal_power_of_ten`. Only the last word is held back while writing.
+///
+/// # Example
+///
+/// ```
+/// use googology::conway_wechsler::{Scale, write_reciprocal_power_of_ten};
+/// let mut planck = String::from("about one point six times ");
+/// write_reciprocal_power_of_ten("35", Scale::Short, &mut planck).unwrap();
+/// assert_eq!("about one point six times one hundred decillionth", planck.as_str());
+/// ```
+pub fn write_reciprocal_power_of_ten<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	out: &mut W
+) -> Result<(), ParseError> {
+	// The minus sign is implied, so no sign may be given.
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	let power = power_from_digits(digits)?;
+	write_reciprocal(&scale, power, out)
+}
+
+/// Gives a name for a number written in scientific notation, such as "6.022e23"
+/// or "1.5e-9". If the number can be written out in full using at most
+/// `max_digits` digits, it is named exactly, as by `decimal_name`. Otherwise
+/// the mantissa is named digit by digit, followed by "times" and the name of
+/// its power of ten, so that even enormous exponents may be named.
+///
+/// # Arguments
+/// 
+/// * `number` - A string slice that holds a decimal mantissa, optionally
+///   followed by "e" or "E" and an exponent. Both may have a sign. If any other
+///   character is present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `max_digits` - The largest number of digits that a number will be
+///   written out in full with.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::conway_wechsler::{Scale, scientific_name};
+/// let exact = scientific_name("6.022e23", Scale::Short, 100).unwrap();
+/// let huge = scientific_name("1.5e3003", Scale::Short, 100).unwrap();
+/// assert_eq!("six hundred two sextillion two hundred quintillion", exact.as_str());
+/// assert_eq!("one point five times one millinillion", huge.as_str());
+/// ```
+pub fn scientific_name(
+	number: &str,
+	scale: Scale,
+	max_digits: usize
+) -> Result<String, ParseError> {
+	name_string(|out| write_scientific_name(number, scale, max_digits, out))
+}
+
+/// Writes a name for a number written in scientific notation, in the same
+/// manner as `scientific_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::conway_wechsler::{Scale, write_scientific_name};
+/// let mut name = String::new();
+/// write_scientific_name("1.616e-35", Scale::Short, 10, &mut name).unwrap();
+/// assert_eq!("one point six one six times one hundred decillionth", name.as_str());
+/// ```
+pub fn write_scientific_name<W: fmt::Write>(
+	number: &str,
+	scale: Scale,
+	max_digits: usize,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_scientific(&scale, number, max_digits, out)
+}
+
+/// Gives a short, rounded name for a number, as a count of the largest
+/// power of one thousand, such as a million or a quattuordecillion. If any
+/// digits are lost to rounding, the name begins with "about". Only the first
+/// few digits are read closely, so this is quick even for numbers whose full
+/// names would be very long.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `sig_figs` - How many significant figures to keep. At least one is
+///   always kept.
+/// * `rounding` - How the digits past the significant figures are rounded.
+/// * `style` - Whether the count of the unit is written with digits or words.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::{MantissaStyle, Rounding};
+/// use googology::conway_wechsler::{Scale, approximate_name};
+/// let digits = format!("12345{}", "0".repeat(41));
+/// let short = approximate_name(&digits, Scale::Short, 2, Rounding::HalfUp, MantissaStyle::Digits);
+/// let words = approximate_name("1999999", Scale::Short, 3, Rounding::HalfUp, MantissaStyle::Words);
+/// assert_eq!("about 1.2 quattuordecillion", short.unwrap().as_str());
+/// assert_eq!("about two million", words.unwrap().as_str());
+/// ```
+pub fn approximate_name(
+	digits: &str,
+	scale: Scale,
+	sig_figs: usize,
+	rounding: Rounding,
+	style: MantissaStyle
+) -> Result<String, ParseError> {
+	name_string(|out| write_approximate_name(digits, scale, sig_figs, rounding, style, out))
+}
+
+/// Writes a short, rounded name for a number, in the same manner as
+/// `approximate_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::{MantissaStyle, Rounding};
+/// use googology::conway_wechsler::{Scale, write_approximate_name};
+/// let mut name = String::new();
+/// let style = MantissaStyle::Digits;
+/// write_approximate_name("2500000000", Scale::LongPeletier, 3, Rounding::Down, style, &mut name).unwrap();
+/// assert_eq!("2.5 milliard", name.as_str());
+/// ```
+pub fn write_approximate_name<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	sig_figs: usize,
+	rounding: Rounding,
+	style: MantissaStyle,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_approximate(&scale, digits, sig_figs, rounding, style, out)
+}
+
+/// Gives a compact name for a number, such as "1.23 Qi" for 1.23 quintillion
+/// or "4.5 UnDc" for 4.5 undecillion. The number is rounded in the same way
+/// as by `approximate_name`, and its unit is abbreviated from its latin
+/// prefixes, so there is no largest unit. Chained prefixes are joined with
+/// '-', as in "1 M-N" for one millinillion.
+///
+/// In the long scales, "K" is placed before the unit for the British scale,
+/// as in "2.5 KM" for 2.5 thousand million, while "d" is placed after it for
+/// the Peletier scale, as in "2.5 Md" for 2.5 milliard.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale should be used.
+/// * `sig_figs` - How many significant figures to keep. At least one is
+///   always kept.
+/// * `rounding` - How the digits past the significant figures are rounded.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::Rounding;
+/// use googology::conway_wechsler::{Scale, compact_name};
+/// let qi = compact_name("1234567890000000000", Scale::Short, 3, Rounding::HalfUp).unwrap();
+/// let undc = compact_name(&format!("45{}", "0".repeat(35)), Scale::Short, 3, Rounding::HalfUp).unwrap();
+/// assert_eq!("1.23 Qi", qi.as_str());
+/// assert_eq!("4.5 UnDc", undc.as_str());
+/// ```
+pub fn compact_name(
+	digits: &str,
+	scale: Scale,
+	sig_figs: usize,
+	rounding: Rounding
+) -> Result<String, ParseError> {
+	name_string(|out| write_compact_name(digits, scale, sig_figs, rounding, out))
+}
+
+/// Writes a compact name for a number, in the same manner as `compact_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::Rounding;
+/// use googology::conway_wechsler::{Scale, write_compact_name};
+/// let mut name = String::new();
+/// write_compact_name("2500000000", Scale::LongPeletier, 3, Rounding::Down, &mut name).unwrap();
+/// assert_eq!("2.5 Md", name.as_str());
+/// ```
+pub fn write_compact_name<W: fmt::Write>(
+	digits: &str,
+	scale: Scale,
+	sig_figs: usize,
+	rounding: Rounding,
+	out: &mut W
+) -> Result<(), ParseError> {
+	let approx = approximate(digits, sig_figs, rounding, Scale::UNIT_DIGITS)?;
+	out.write_str(approx.decimal().as_str())?;
+
+	// The suffix is made from the same words that full_name would give.
+	let words = zillion_words(approx.unit, scale);
+	if !words.is_empty() { out.write_char(' ')?; }
+	for word in words {
+		match word {
+			Word::Thousand    => out.write_char('K')?,
+			Word::Zillion(p)  => write_zillion_abbreviations(p, out)?,
+			Word::Zilliard(p) => {
+				write_zillion_abbreviations(p, out)?;
+				out.write_char('d')?;
+			}
+			_ => return Err(ParseError::InternalError),
+		}
+	}
+
+	Ok(())
+}
+
+// The reverse of zillion_prefix chaining. Reads a single word such as
+// "millinillion" or "billiard", giving back the value of each latin prefix
+// (most significant first), along with whether or not the word ends in "ard".
+fn parse_zillion_word(
+	word: &str,
+	latin: &HashMap<String, usize>
+) -> Result<(Vec<usize>, bool), ParseError> {
+	let (stem, ard) = if let Some(stem) = word.strip_suffix("on") { (stem, false) }
+	else if let Some(stem) = word.strip_suffix("ard") { (stem, true) }
+	else { return Err(ParseError::UnknownWord); };
+
+	// Every prefix ends in "illi", and no latin prefix contains "illi" on its
+	// own, so splitting on it recovers each of the prefixes exactly.
+	let stem = stem.strip_suffix("illi").ok_or(ParseError::UnknownWord)?;
+	let prefixes = stem
+		.split("illi")
+		.map(|p| latin.get(p).copied().ok_or(ParseError::UnknownWord))
+		.collect::<Result<Vec<usize>, ParseError>>()?;
+
+	// A leading "nilli" is never produced by zillion_number.
+	if prefixes[0] == 0 { return Err(ParseError::InvalidName); }
+	Ok((prefixes, ard))
+}
+
+// The reverse of zillion_number. Reads the zillion name at the start of words,
+// giving back which power of 1000 it represents and how many words were used.
+// If words does not begin with a zillion name, (0, 0) is returned.
+fn parse_zillion(
+	words: &[&str],
+	scale: Scale,
+	latin: &HashMap<String, usize>
+) -> Result<(BigUint, usize), ParseError> {
+	let (thousand, rest) = match words.split_first() {
+		Some((&"thousand", rest)) => (1, rest),
+		_ => (0, words),
+	};
+
+	let (prefixes, ard) = match rest.first().map(|w| parse_zillion_word(w, latin)) {
+		Some(Ok(zillion)) => zillion,
+		Some(Err(ParseError::UnknownWord)) | None => {
+			return Ok((BigUint::from(thousand), thousand));
+		}
+		Some(Err(e)) => return Err(e),
+	};
+
+	let power = prefixes
+		.iter()
+		.fold(BigUint::zero(), |p, &n| p * 1000u32 + n);
 
-	// Add zillions in reverse order.
-	let mut zillions = Vec::new();
-	while !power.is_zero() {
-		let zillion = (&power % 1000u32)
-			.to_usize()
-			.ok_or(ParseError::InternalError)
-			.and_then(zillion_prefix)?;
-		zillions.push(zillion);
-		power /= 1000u32;
+	// Undo the adjustments made for the long scales.
+	let num = match (scale, thousand, ard) {
+		(Scale::Short, 0, false) => power + 1u32,
+		(Scale::LongBritish, 0, false)
+		| (Scale::LongPeletier, 0, false) => power * 2u32,
+		(Scale::LongBritish, 1, false)
+		| (Scale::LongPeletier, 0, true) => power * 2u32 + 1u32,
+		// A lone "thousand" followed by some unrelated zillion.
+		(_, 1, _) => return Ok((BigUint::one(), 1)),
+		(_, _, _) => return Err(ParseError::InvalidName),
+	};
+
+	Ok((num, thousand + 1))
+}
+
+// Gives the error for a word found where no such word could be in a name.
+fn unexpected_word(word: &str, latin: &HashMap<String, usize>) -> ParseError {
+	let known = ["zero", "hundred", "thousand"].contains(&word)
+		|| parse_myriad(&[word]).is_ok_and(|(num, _)| num > 0)
+		|| parse_zillion_word(word, latin).is_ok();
+
+	if known { ParseError::InvalidName } else { ParseError::UnknownWord }
+}
+
+/// Reads a name given by `full_name`, and gives back the digits of the number
+/// that it describes. This is the reverse of `full_name`, and gives back the
+/// digits without any leading zeroes.
+///
+/// # Arguments
+/// 
+/// * `name` - A string slice holding the name of a number, with each word in
+///   lowercase and separated by whitespace. If any word is not part of the
+///   Conway-Wechsler system, or the words are not in an order that `full_name`
+///   would output, this function will return an Err. If the number would have
+///   more than 2^24 digits, `ParseError::InputTooLarge` is returned instead;
+///   `parse_power_of_ten` can read the names of such large numbers.
+/// * `scale` - An enumerated value to determine which scale the name was
+///   written in. The same name may describe different numbers depending on
+///   the scale, such as "one billion".
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, parse_name};
+/// let milliard = parse_name("nineteen milliard forty two", Scale::LongPeletier).unwrap();
+/// let billion = parse_name("nineteen billion forty two", Scale::Short).unwrap();
+/// assert_eq!("19000000042", milliard.as_str());
+/// assert_eq!("19000000042", billion.as_str());
+/// ```
+pub fn parse_name(name: &str, scale: Scale) -> Result<String, ParseError> {
+	let words = name.split_whitespace().collect::<Vec<&str>>();
+	if words.is_empty() { return Err(ParseError::Empty); }
+	if words == ["zero"] { return Ok(String::from("0")); }
+
+	let latin = latin_values();
+
+	// Break the name into groups of a number in the range [1, 999] followed
+	// by the power of 1000 it is multiplied by. Each group must describe a
+	// smaller power of 1000 than the one before it.
+	let mut groups: Vec<(usize, usize)> = Vec::new();
+	let mut i = 0;
+	while i < words.len() {
+		let (leading, used) = parse_myriad(&words[i..])?;
+		if leading == 0 { return Err(unexpected_word(words[i], &latin)); }
+		if leading > 999 { return Err(ParseError::InvalidName); }
+		i += used;
+
+		let (zillion, used) = parse_zillion(&words[i..], scale, &latin)?;
+		let zillion = zillion.to_usize().ok_or(ParseError::InputTooLarge)?;
+		i += used;
+
+		if groups.last().is_some_and(|&(_, last)| zillion >= last) {
+			return Err(ParseError::InvalidName);
+		}
+		groups.push((leading, zillion));
 	}
 
-	for z in zillions.iter().rev() {
-		output.push_str(z.as_str());
+	// Write out each group, filling in the gaps between them with zeroes. The
+	// number of digits is known from the first group, so a name too large to
+	// write out is refused before anything is allocated.
+	let (leading, mut last) = groups[0];
+	let len = last
+		.checked_mul(3)
+		.and_then(|n| n.checked_add(3))
+		.filter(|&n| n <= MAX_PARSED_DIGITS)
+		.ok_or(ParseError::InputTooLarge)?;
+	let mut digits = String::with_capacity(len);
+
+	digits.push_str(leading.to_string().as_str());
+	for &(leading, zillion) in &groups[1..] {
+		digits.push_str("000".repeat(last - zillion - 1).as_str());
+		digits.push_str(format!("{:03}", leading).as_str());
+		last = zillion;
 	}
 
-	output.push_str(suffix);
-	Ok(output)
+	digits.push_str("000".repeat(last).as_str());
+	Ok(digits)
+}
+
+/// Reads a name given by `power_of_ten`, and gives back the digits of the
+/// power of ten that it describes. This is the reverse of `power_of_ten`, and
+/// can be used for names of numbers too large to write out as digits.
+///
+/// # Arguments
+/// 
+/// * `name` - A string slice holding the name of a power of ten, with each
+///   word in lowercase and separated by whitespace. If the name is not one
+///   that `power_of_ten` would output, this function will return an Err.
+/// * `scale` - An enumerated value to determine which scale the name was
+///   written in.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::conway_wechsler::{Scale, parse_power_of_ten};
+/// let googol = parse_power_of_ten("ten duotrigintillion", Scale::Short).unwrap();
+/// let milliard = parse_power_of_ten("one milliard", Scale::LongPeletier).unwrap();
+/// assert_eq!("100", googol.as_str());
+/// assert_eq!("9", milliard.as_str());
+/// ```
+pub fn parse_power_of_ten(name: &str, scale: Scale) -> Result<String, ParseError> {
+	let words = name.split_whitespace().collect::<Vec<&str>>();
+	let latin = latin_values();
+
+	// Get the leading word (e.g. "ten" in "ten million")
+	let (leading, rest) = match words.as_slice() {
+		[] => return Err(ParseError::Empty),
+		["one", "hundred", rest @ ..] => (2u32, rest),
+		["one", rest @ ..] => (0u32, rest),
+		["ten", rest @ ..] => (1u32, rest),
+		[word, ..] => return Err(unexpected_word(word, &latin)),
+	};
+
+	let (zillion, used) = parse_zillion(rest, scale, &latin)?;
+	if let Some(word) = rest.get(used) {
+		return Err(unexpected_word(word, &latin));
+	}
+
+	let power = zillion * 3u32 + leading;
+	Ok(power.to_str_radix(10))
+}
+
+impl NamingSystem for ConwayWechsler {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits, self.scale)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits, self.scale)
+	}
+
+	fn parse_name(&self, name: &str) -> Result<String, ParseError> {
+		parse_name(name, self.scale)
+	}
+
+	fn parse_power_of_ten(&self, name: &str) -> Result<String, ParseError> {
+		parse_power_of_ten(name, self.scale)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::common::random_digits;
+	use crate::{Case, Separator};
 
 	#[test]
 	fn very_small_numbers() -> Result<(), ParseError> {
@@ -318,4 +1198,406 @@ mod tests {
 		assert_eq!("ten sedecilliard", googol_lp.as_str());
 		Ok(())
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn parse_large_numbers() -> Result<(), ParseError> {
+		let billion = parse_name("one billion", Scale::Short)?;
+		let milliard = parse_name("one milliard", Scale::LongPeletier)?;
+		let thousand_million = parse_name("one thousand million", Scale::LongBritish)?;
+		let millinillion = parse_name("two millinillion", Scale::Short)?;
+		assert_eq!("1000000000", billion.as_str());
+		assert_eq!("1000000000", milliard.as_str());
+		assert_eq!("1000000000", thousand_million.as_str());
+		assert_eq!(format!("2{}", "0".repeat(3003)), millinillion);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_invalid_names() {
+		let empty = parse_name(" ", Scale::Short);
+		let unknown = parse_name("one gazillion", Scale::Short);
+		let milliard = parse_name("one milliard", Scale::Short);
+		let reordered = parse_name("one thousand two million", Scale::Short);
+		assert_eq!(Err(ParseError::Empty), empty);
+		assert_eq!(Err(ParseError::UnknownWord), unknown);
+		assert_eq!(Err(ParseError::InvalidName), milliard);
+		assert_eq!(Err(ParseError::InvalidName), reordered);
+
+		// A name this short could otherwise ask for trillions of digits.
+		let huge = parse_name("one millinillinillinillinillion", Scale::Short);
+		assert_eq!(Err(ParseError::InputTooLarge), huge);
+	}
+
+	#[test]
+	fn parse_round_trip() -> Result<(), ParseError> {
+		let mut seed: u64 = 42;
+		for len in (1..80).chain(3000..3012) {
+			let digits = random_digits(len, &mut seed);
+
+			for &scale in &[Scale::Short, Scale::LongBritish, Scale::LongPeletier] {
+				let name = full_name(digits.as_str(), scale)?;
+				assert_eq!(digits, parse_name(name.as_str(), scale)?);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn parse_large_powers() -> Result<(), ParseError> {
+		let googol_lb = parse_power_of_ten("ten thousand sedecillion", Scale::LongBritish)?;
+		let googol_lp = parse_power_of_ten("ten sedecilliard", Scale::LongPeletier)?;
+		let thousand = parse_power_of_ten("one hundred thousand", Scale::Short)?;
+		let invalid = parse_power_of_ten("two million", Scale::Short);
+		assert_eq!("100", googol_lb.as_str());
+		assert_eq!("100", googol_lp.as_str());
+		assert_eq!("5", thousand.as_str());
+		assert_eq!(Err(ParseError::InvalidName), invalid);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_power_round_trip() -> Result<(), ParseError> {
+		let powers = (0..200u32)
+			.map(|p| p.to_string())
+			.chain((1..40).map(|n| format!("7{}1", "0".repeat(n))))
+			.chain((1..40).map(|n| format!("3{}", "9".repeat(n))));
+
+		for power in powers {
+			for &scale in &[Scale::Short, Scale::LongBritish, Scale::LongPeletier] {
+				let name = power_of_ten(power.as_str(), scale)?;
+				assert_eq!(power, parse_power_of_ten(name.as_str(), scale)?);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn write_errors() {
+		// A writer with no room left, which fails on every write.
+		let mut full = [0u8; 0];
+		let mut full = &mut full[..];
+		let write_failed = write_full_name_io("42", Scale::Short, &mut full)
+			.map_err(|e| e.kind());
+		assert_eq!(Err(std::io::ErrorKind::WriteZero), write_failed);
+
+		let mut bytes = Vec::new();
+		let invalid = write_power_of_ten_io("4x", Scale::Short, &mut bytes)
+			.map_err(|e| e.into_inner().map(|e| e.to_string()));
+		assert_eq!(Err(Some(ParseError::InvalidDigit.to_string())), invalid);
+		assert!(bytes.is_empty());
+	}
+
+	#[test]
+	fn read_digits() -> Result<(), ParseError> {
+		let mut cursor = std::io::Cursor::new("000019000000042");
+		let mut bytes = Vec::new();
+		write_full_name_from_reader(&mut cursor, Scale::LongPeletier, &mut bytes)
+			.map_err(|_| ParseError::InternalError)?;
+		assert_eq!(b"nineteen milliard forty two", bytes.as_slice());
+
+		let mut cursor = std::io::Cursor::new("12000000000042\n");
+		let mut bytes = Vec::new();
+		let invalid = write_full_name_from_reader(&mut cursor, Scale::Short, &mut bytes);
+		assert_eq!(Some(std::io::ErrorKind::InvalidInput), invalid.err().map(|e| e.kind()));
+		assert!(bytes.is_empty());
+
+		let mut name = String::new();
+		let zeroes = write_full_name_from_iter(b"0000".iter().copied(), 4, Scale::Short, &mut name);
+		assert_eq!(Ok(()), zeroes);
+		assert_eq!("zero", name.as_str());
+		Ok(())
+	}
+
+	#[test]
+	fn words_match_full_name() -> Result<(), ParseError> {
+		assert_eq!("millinillion", Word::Zillion(1000).to_string());
+		assert_eq!("billiard", Word::Zilliard(2).to_string());
+
+		let numbers = ["0", "007", "1000", "1000000000", "123456789012345678901234"];
+		for &digits in numbers.iter() {
+			for &scale in &[Scale::Short, Scale::LongBritish, Scale::LongPeletier] {
+				let joined = words(digits, scale)?
+					.map(|w| w.to_string())
+					.collect::<Vec<String>>()
+					.join(" ");
+				assert_eq!(full_name(digits, scale)?, joined);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn native_integers() -> Result<(), ParseError> {
+		assert_eq!("zero", full_name_of(0u8, Scale::Short)?);
+		assert_eq!(full_name("18446744073709551615", Scale::Short)?, full_name_of(u64::MAX, Scale::Short)?);
+		assert_eq!(
+			full_name(u128::MAX.to_string().as_str(), Scale::LongBritish)?,
+			full_name_of(u128::MAX, Scale::LongBritish)?
+		);
+		let big = BigUint::from(10u32).pow(3003);
+		assert_eq!("one millinillion", full_name_of(big, Scale::Short)?);
+
+		for &scale in &[Scale::Short, Scale::LongBritish, Scale::LongPeletier] {
+			for power in [0u32, 1, 2, 3, 6, 9, 100, 3003, 1_000_000].iter() {
+				assert_eq!(
+					power_of_ten(power.to_string().as_str(), scale)?,
+					power_of_ten_of(&BigUint::from(*power), scale)?
+				);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn ordinals() -> Result<(), ParseError> {
+		let expected = [
+			("0", "zeroth"), ("1", "first"), ("2", "second"), ("3", "third"),
+			("4", "fourth"), ("5", "fifth"), ("8", "eighth"), ("9", "ninth"),
+			("11", "eleventh"), ("12", "twelfth"), ("20", "twentieth"),
+			("21", "twenty first"), ("99", "ninety ninth"), ("100", "one hundredth"),
+			("1000", "one thousandth"), ("1000000", "one millionth"),
+			("1000000000", "one billionth"),
+		];
+		for &(digits, name) in expected.iter() {
+			assert_eq!(name, ordinal_name(digits, Scale::Short)?);
+		}
+
+		assert_eq!("one milliardth", ordinal_name("1000000000", Scale::LongPeletier)?);
+		assert_eq!("one thousand millionth", ordinal_name("1000000000", Scale::LongBritish)?);
+		assert_eq!("forty two billion seventh", ordinal_name("42000000007", Scale::Short)?);
+		assert_eq!(Err(ParseError::InvalidDigit), ordinal_name("1st", Scale::Short));
+		Ok(())
+	}
+
+	#[test]
+	fn negative_numbers() -> Result<(), ParseError> {
+		assert_eq!("minus forty two billion", full_name("-42000000000", Scale::Short)?);
+		assert_eq!("negative forty two milliard", signed_full_name("-42000000000", Scale::LongPeletier, "negative")?);
+		assert_eq!("forty two", full_name("+42", Scale::Short)?);
+		assert_eq!("zero", full_name("-000", Scale::Short)?);
+		assert_eq!(Err(ParseError::Empty), full_name("-", Scale::Short));
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("--1", Scale::Short));
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("1-", Scale::Short));
+
+		assert_eq!("one thousand", power_of_ten("+3", Scale::Short)?);
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-0", Scale::Short));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-3", Scale::Short));
+		assert_eq!(Err(ParseError::InvalidDigit), power_of_ten("-x", Scale::Short));
+
+		// The functions which cannot name a negative number say so, rather
+		// than calling the sign an invalid digit.
+		let negative = Err(ParseError::NegativeNumber);
+		assert_eq!(Some(ParseError::NegativeNumber), words("-42", Scale::Short).err());
+		assert_eq!(negative, ordinal_name("-42", Scale::Short));
+		assert_eq!(negative, approximate_name("-42", Scale::Short, 2, Rounding::HalfUp, MantissaStyle::Digits));
+		assert_eq!(negative, compact_name("-42", Scale::Short, 2, Rounding::HalfUp));
+		assert_eq!("zeroth", ordinal_name("-0", Scale::Short)?);
+		assert_eq!("forty second", ordinal_name("+42", Scale::Short)?);
+		assert_eq!(Err(ParseError::InvalidDigit), ordinal_name("-4x", Scale::Short));
+		Ok(())
+	}
+
+	#[test]
+	fn decimals() -> Result<(), ParseError> {
+		let digits = |n| decimal_name(n, Scale::Short, FractionStyle::Digits);
+		let units = |n| decimal_name(n, Scale::Short, FractionStyle::Units);
+
+		assert_eq!("twelve point five", digits("12.5")?);
+		assert_eq!("zero point zero zero one", digits(".001")?);
+		assert_eq!("minus three point one four", digits("-3.14")?);
+		assert_eq!("forty two", digits("42.")?);
+
+		assert_eq!("twelve and five tenths", units("12.5")?);
+		assert_eq!("one millionth", units("0.000001")?);
+		assert_eq!("three hundred twenty five thousandths", units("0.325")?);
+		assert_eq!("seven ten thousandths", units("0.0007")?);
+		assert_eq!("one billion and one hundredth", units("1000000000.01")?);
+		assert_eq!("twelve", units("12.000")?);
+		assert_eq!("zero", units("-0.0")?);
+
+		let milliardth = decimal_name("0.000000001", Scale::LongPeletier, FractionStyle::Units)?;
+		let thousand_millionths = decimal_name("0.000000002", Scale::LongBritish, FractionStyle::Units)?;
+		assert_eq!("one milliardth", milliardth);
+		assert_eq!("two thousand millionths", thousand_millionths);
+
+		assert_eq!(Err(ParseError::Empty), units("."));
+		assert_eq!(Err(ParseError::InvalidDigit), units("1.2.3"));
+		assert_eq!(Err(ParseError::InvalidDigit), digits("1,5"));
+		Ok(())
+	}
+
+	#[test]
+	fn reciprocal_powers() -> Result<(), ParseError> {
+		assert_eq!("one", reciprocal_power_of_ten("0", Scale::Short)?);
+		assert_eq!("one tenth", reciprocal_power_of_ten("1", Scale::Short)?);
+		assert_eq!("one hundredth", reciprocal_power_of_ten("2", Scale::Short)?);
+		assert_eq!("one thousandth", reciprocal_power_of_ten("3", Scale::Short)?);
+		assert_eq!("one ten thousandth", reciprocal_power_of_ten("4", Scale::Short)?);
+		assert_eq!("one trillionth", reciprocal_power_of_ten("12", Scale::Short)?);
+		assert_eq!("one ten sextillionth", reciprocal_power_of_ten("22", Scale::Short)?);
+		assert_eq!("one milliardth", reciprocal_power_of_ten("9", Scale::LongPeletier)?);
+		assert_eq!("one thousand millionth", reciprocal_power_of_ten("9", Scale::LongBritish)?);
+		assert_eq!("one millinillionth", reciprocal_power_of_ten("3003", Scale::Short)?);
+		assert_eq!(Err(ParseError::InvalidDigit), reciprocal_power_of_ten("-3", Scale::Short));
+
+		// Each reciprocal should name the same fraction as decimal_name.
+		for power in 1..40 {
+			let decimal = format!("0.{}1", "0".repeat(power - 1));
+			assert_eq!(
+				decimal_name(decimal.as_str(), Scale::LongBritish, FractionStyle::Units)?,
+				reciprocal_power_of_ten(power.to_string().as_str(), Scale::LongBritish)?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn scientific_notation() -> Result<(), ParseError> {
+		let name = |n| scientific_name(n, Scale::Short, 30);
+		assert_eq!("six hundred two sextillion two hundred quintillion", name("6.022e23")?);
+		assert_eq!("six hundred two sextillion two hundred quintillion", name("6022E20")?);
+		assert_eq!("minus one thousand five hundred", name("-1.5e+3")?);
+		assert_eq!("zero point zero zero one five", name("1.5e-3")?);
+		assert_eq!("twelve point five", name("0.125e2")?);
+		assert_eq!("fifteen", name("1.50e1")?);
+		assert_eq!("forty two", name("42")?);
+		assert_eq!("zero", name("0.0e99999999999999999999")?);
+
+		assert_eq!("one point five times one nonillion", name("1.5e30")?);
+		assert_eq!("one point five times one hundredth", scientific_name("1.5e-2", Scale::Short, 1)?);
+		assert_eq!(
+			"six point zero two two times one hundred sextillion",
+			scientific_name("6.022e23", Scale::Short, 10)?
+		);
+		assert_eq!(
+			format!("one point five times ten {}duotrigintatrecentillion", "trestrigintatrecentilli".repeat(3)),
+			name("1.5e1000000000000")?
+		);
+		assert_eq!(
+			"one times one milliardth",
+			scientific_name("1e-9", Scale::LongPeletier, 5)?
+		);
+
+		assert_eq!(Err(ParseError::Empty), name("1.5e"));
+		assert_eq!(Err(ParseError::Empty), name("e5"));
+		assert_eq!(Err(ParseError::InvalidDigit), name("1.5e2.5"));
+		assert_eq!(Err(ParseError::InvalidDigit), name("1.5x2"));
+		Ok(())
+	}
+
+	#[test]
+	fn approximate_names() -> Result<(), ParseError> {
+		let digits = |n, sig, rounding| approximate_name(n, Scale::Short, sig, rounding, MantissaStyle::Digits);
+		assert_eq!("0", digits("000", 3, Rounding::HalfUp)?);
+		assert_eq!("42", digits("42", 3, Rounding::HalfUp)?);
+		assert_eq!("about 120", digits("123", 2, Rounding::Down)?);
+		assert_eq!("1.5 thousand", digits("1500", 2, Rounding::HalfUp)?);
+		assert_eq!("about 1.24 million", digits("1235000", 3, Rounding::HalfEven)?);
+		assert_eq!("about 1.22 million", digits("1225000", 3, Rounding::HalfEven)?);
+		assert_eq!("about 1.23 million", digits("1225001", 3, Rounding::HalfEven)?);
+		assert_eq!("about 1.22 million", digits("1225001", 3, Rounding::Down)?);
+		assert_eq!("about 1.23 million", digits("1220001", 3, Rounding::Up)?);
+		assert_eq!("about 1 billion", digits("999999999", 2, Rounding::HalfUp)?);
+		assert_eq!("about 100 thousand", digits("123456", 1, Rounding::HalfUp)?);
+		assert_eq!("about 100 thousand", digits("123456", 0, Rounding::HalfUp)?);
+
+		// Only the leading digits should matter, however many there are.
+		let huge = format!("7{}1", "0".repeat(3002));
+		assert_eq!("about 7 millinillion", digits(huge.as_str(), 3, Rounding::Down)?);
+		assert_eq!("about 7.01 millinillion", digits(huge.as_str(), 3, Rounding::Up)?);
+
+		let british = approximate_name("2500000000", Scale::LongBritish, 2, Rounding::HalfUp, MantissaStyle::Words)?;
+		assert_eq!("two point five thousand million", british);
+		let words = approximate_name("1234567", Scale::Short, 3, Rounding::HalfUp, MantissaStyle::Words)?;
+		assert_eq!("about one point two three million", words);
+
+		assert_eq!(Err(ParseError::Empty), digits("", 3, Rounding::HalfUp));
+		assert_eq!(Err(ParseError::InvalidDigit), digits("12a", 3, Rounding::HalfUp));
+		Ok(())
+	}
+
+	#[test]
+	fn compact_names() -> Result<(), ParseError> {
+		let compact = |power: usize, scale| {
+			let digits = format!("1{}", "0".repeat(power));
+			compact_name(digits.as_str(), scale, 3, Rounding::HalfUp)
+		};
+
+		let short = [
+			(0, "1"), (3, "1 K"), (6, "1 M"), (9, "1 B"), (12, "1 T"), (15, "1 Qa"),
+			(18, "1 Qi"), (21, "1 Sx"), (24, "1 Sp"), (27, "1 Oc"), (30, "1 No"),
+			(33, "1 Dc"), (36, "1 UnDc"), (39, "1 DuDc"), (63, "1 Vg"),
+			(303, "1 Ce"), (306, "1 UnCe"), (3003, "1 M-N"), (3006, "1 M-M"),
+			(3000003, "1 M-N-N"),
+		];
+		for &(power, name) in short.iter() {
+			assert_eq!(name, compact(power, Scale::Short)?);
+		}
+
+		assert_eq!("1 KM", compact(9, Scale::LongBritish)?);
+		assert_eq!("1 Md", compact(9, Scale::LongPeletier)?);
+		assert_eq!("1 B", compact(12, Scale::LongPeletier)?);
+		assert_eq!("10 Bd", compact(16, Scale::LongPeletier)?);
+
+		// Suffixes should be built from the same prefixes as full names.
+		assert_eq!("999 TrTgCe", compact_name(&format!("999{}", "0".repeat(402)), Scale::Short, 3, Rounding::Down)?);
+		assert_eq!("1 K", compact_name("999", Scale::Short, 2, Rounding::HalfUp)?);
+		assert_eq!("999", compact_name("999", Scale::Short, 3, Rounding::HalfUp)?);
+		assert_eq!(Err(ParseError::InvalidDigit), compact_name("12.3", Scale::Short, 3, Rounding::Down));
+		Ok(())
+	}
+
+	#[test]
+	fn styled_names() -> Result<(), ParseError> {
+		let numbers = ["0", "7", "42", "101", "1000000", "123456789012345678901234"];
+		for &digits in numbers.iter() {
+			for &scale in &[Scale::Short, Scale::LongBritish, Scale::LongPeletier] {
+				assert_eq!(full_name(digits, scale)?, full_name_with(digits, scale, &Style::default())?);
+			}
+		}
+
+		let hyphens = Style { hyphenate: true, ..Style::default() };
+		assert_eq!("twenty-one", full_name_with("21", Scale::Short, &hyphens)?);
+		assert_eq!("twenty", full_name_with("20", Scale::Short, &hyphens)?);
+		assert_eq!("seventeen", full_name_with("17", Scale::Short, &hyphens)?);
+		assert_eq!("ninety-nine million ninety", full_name_with("99000090", Scale::Short, &hyphens)?);
+
+		let british = Style { hyphenate: true, and: true, separator: Separator::Comma, case: Case::Lower };
+		let name = |digits| full_name_with(digits, Scale::LongBritish, &british);
+		assert_eq!("one hundred", name("100")?);
+		assert_eq!("one hundred and one", name("101")?);
+		assert_eq!("one thousand and one", name("1001")?);
+		assert_eq!("one thousand, one hundred", name("1100")?);
+		assert_eq!("one million and forty-two", name("1000042")?);
+		assert_eq!("one thousand million, two hundred and three", name("1000000203")?);
+		assert_eq!("one thousand million and three", name("1000000003")?);
+		assert_eq!("minus one hundred and one", name("-101")?);
+
+		let commas = Style { separator: Separator::Comma, ..Style::default() };
+		let name = full_name_with("1002003004", Scale::Short, &commas)?;
+		assert_eq!("one billion, two million, three thousand, four", name);
+		Ok(())
+	}
+
+	#[test]
+	fn cased_names() -> Result<(), ParseError> {
+		let cased = |digits, case| full_name_with(digits, Scale::Short, &Style { case, ..Style::default() });
+		assert_eq!("one hundred twenty three million", cased("123000000", Case::Lower)?);
+		assert_eq!("One hundred twenty three million", cased("123000000", Case::Sentence)?);
+		assert_eq!("One Hundred Twenty Three Million", cased("123000000", Case::Title)?);
+		assert_eq!("ONE HUNDRED TWENTY THREE MILLION", cased("123000000", Case::Upper)?);
+
+		// Chained zillions are a single word, however they are written.
+		let millinillion = format!("1{}", "0".repeat(3003));
+		assert_eq!("One Millinillion", cased(millinillion.as_str(), Case::Title)?);
+		assert_eq!("Minus Seven", cased("-7", Case::Title)?);
+		assert_eq!("Minus seven", cased("-7", Case::Sentence)?);
+
+		let title = Style { hyphenate: true, and: true, separator: Separator::Comma, case: Case::Title };
+		let name = full_name_with("1000101", Scale::LongPeletier, &title)?;
+		assert_eq!("One Million, One Hundred and One", name);
+		let upper = Style { case: Case::Upper, ..title };
+		assert_eq!("ONE HUNDRED AND FORTY-TWO", full_name_with("142", Scale::Short, &upper)?);
+		Ok(())
+	}
+}
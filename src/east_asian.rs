@@ -0,0 +1,392 @@
+//! Methods for the myriad systems used in Chinese, Japanese and Korean, where
+//! the digits of a number are grouped by four rather than three. Each group is
+//! named with the characters for ten (十), hundred (百) and thousand (千), and
+//! is followed by a unit: 万 for 10^4, 億 for 10^8, 兆 for 10^12, and so on up
+//! to 無量大数, whose modern value is 10^68.
+//!
+//! Names may be written either in characters, such as "一億二千万", or in the
+//! Hepburn romanisation of their Japanese readings, such as "ichi oku nisen
+//! man". The characters are given in their Japanese forms.
+//!
+//! The ancient Chinese text 數術記遺 describes three older interpretations of
+//! the ten units from 億 to 載, each of which is provided as a `Variant`:
+//! * 下数 (`Variant::Lower`), where each unit is ten times the last. 10^5 is
+//!   一億, and 載 is 10^14.
+//! * 中数 (`Variant::Middle`), where each unit is 10^8 times the last. 10^16
+//!   is 一兆, and 載 is 10^80.
+//! * 上数 (`Variant::Upper`), where each unit is the square of the last, as in
+//!   the Knuth -yllion system. 10^32 is 一京, and 載 is 10^4096.
+//!
+//! The units from 極 onwards were only added later, with the modern values, so
+//! these variants end at 載. A number too large for the last unit of a variant
+//! gives `ParseError::InputTooLarge`.
+
+use num_traits::cast::ToPrimitive;
+
+use crate::common::{
+	num_from_slice,
+	power_from_digits,
+	split_number
+};
+
+use crate::{NamingSystem, ParseError};
+
+// The units above the thousands, starting from 万. The first eleven are the
+// ones described by 數術記遺, after which there are only modern values.
+static UNIT_CHARACTERS: [&str; 17] = [
+	"万", "億", "兆", "京", "垓", "秭", "穣", "溝", "澗", "正", "載", "極",
+	"恒河沙", "阿僧祇", "那由他", "不可思議", "無量大数"
+];
+
+static UNIT_ROMAJI: [&str; 17] = [
+	"man", "oku", "chō", "kei", "gai", "jo", "jō", "kō", "kan", "sei", "sai",
+	"goku", "gōgasha", "asōgi", "nayuta", "fukashigi", "muryōtaisū"
+];
+
+// How many of the units are given a value by the older variants.
+const CLASSICAL_UNITS: usize = 11;
+
+static DIGIT_CHARACTERS: [&str; 10] = [
+	"", "一", "二", "三", "四", "五", "六", "七", "八", "九"
+];
+
+static DIGIT_ROMAJI: [&str; 10] = [
+	"", "ichi", "ni", "san", "yon", "go", "roku", "nana", "hachi", "kyū"
+];
+
+/// A parameter for East Asian functions which indicates the value of each unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+	/// The modern system, where each unit is 10^4 times the last. 10^8 will be
+	/// called 一億, and numbers of 10^72 or more cannot be named.
+	Myriad,
+	/// 下数, where each unit from 億 is ten times the last. 10^5 will be called
+	/// 一億, and numbers of 10^15 or more cannot be named.
+	Lower,
+	/// 中数, where each unit from 億 is 10^8 times the last. 10^16 will be
+	/// called 一兆, and numbers of 10^88 or more cannot be named.
+	Middle,
+	/// 上数, where each unit from 億 is the square of the last. 10^32 will be
+	/// called 一京, and numbers of 10^8192 or more cannot be named.
+	Upper,
+}
+
+/// A parameter for East Asian functions which indicates how names are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+	/// The name is written in characters, such as "一億二千万".
+	Characters,
+	/// The name is written in the Hepburn romanisation of its Japanese
+	/// reading, such as "ichi oku nisen man".
+	Romaji,
+}
+
+/// The East Asian myriad system as a `NamingSystem`, using the given variant
+/// and script for each of its names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EastAsian {
+	pub variant: Variant,
+	pub script: Script,
+}
+
+// A name is made of numbers below one myriad, each of which may be followed by
+// a unit, given as an index into UNIT_CHARACTERS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Term {
+	Number(usize),
+	Unit(usize),
+}
+
+// Gives the most digits that a number may have in the given variant.
+fn max_digits(variant: Variant) -> usize {
+	match variant {
+		Variant::Myriad => 4*(UNIT_CHARACTERS.len() + 1),
+		Variant::Lower  => CLASSICAL_UNITS + 4,
+		Variant::Middle => 8*CLASSICAL_UNITS,
+		Variant::Upper  => 1 << (CLASSICAL_UNITS + 2),
+	}
+}
+
+// Gives the terms for a number of any size below 10^8, using 万 alone.
+fn myriad_terms(digits: &str, terms: &mut Vec<Term>) {
+	if digits.is_empty() { return; }
+	let split = digits.len().saturating_sub(4);
+	let high = if split > 0 { num_from_slice(digits, 0, split) } else { 0 };
+	let low = num_from_slice(digits, split, digits.len() - split);
+
+	if high > 0 { terms.extend([Term::Number(high), Term::Unit(0)]); }
+	if low > 0 { terms.push(Term::Number(low)); }
+}
+
+// Gives the terms for a number with no leading zeroes in the 上数 variant. The
+// largest unit below the number splits it in two, and each half is named in
+// the same way.
+fn upper_terms(digits: &str, terms: &mut Vec<Term>) {
+	let digits = digits.trim_start_matches('0');
+	if digits.len() <= 8 { return myriad_terms(digits, terms); }
+
+	// Unit i has the value 10^(2^(i+2)).
+	let mut unit = 1;
+	while 1 << (unit + 3) < digits.len() { unit += 1; }
+	let split = digits.len() - (1 << (unit + 2));
+
+	upper_terms(&digits[..split], terms);
+	terms.push(Term::Unit(unit));
+	upper_terms(&digits[split..], terms);
+}
+
+// Gives the terms for a number with no sign or leading zeroes, which must not
+// have more digits than max_digits allows.
+fn terms(digits: &str, variant: Variant) -> Vec<Term> {
+	let mut terms = Vec::new();
+	match variant {
+		Variant::Myriad | Variant::Middle => {
+			// Both group the digits evenly, by four or by eight.
+			let size = if variant == Variant::Myriad { 4 } else { 8 };
+			let groups = digits.len().div_ceil(size);
+			let mut index = 0;
+			for group in (0..groups).rev() {
+				let end = digits.len() - size*group;
+				let chunk = &digits[index..end];
+				index = end;
+
+				if chunk.bytes().all(|b| b == b'0') { continue; }
+				if variant == Variant::Myriad {
+					terms.push(Term::Number(num_from_slice(chunk, 0, chunk.len())));
+					if group > 0 { terms.push(Term::Unit(group - 1)); }
+				} else {
+					myriad_terms(chunk, &mut terms);
+					if group > 0 { terms.push(Term::Unit(group)); }
+				}
+			}
+		}
+		Variant::Lower => {
+			// Every digit above the thousands has a unit of its own.
+			let split = digits.len().saturating_sub(4);
+			for (i, d) in digits[..split].bytes().enumerate() {
+				if d == b'0' { continue; }
+				terms.push(Term::Number(usize::from(d - b'0')));
+				terms.push(Term::Unit(split - i - 1));
+			}
+			let low = num_from_slice(digits, split, digits.len() - split);
+			if low > 0 { terms.push(Term::Number(low)); }
+		}
+		Variant::Upper => upper_terms(digits, &mut terms),
+	}
+	terms
+}
+
+// Writes a number below one myriad in characters. A one is left unwritten
+// before 十, 百 and 千, but not on its own.
+fn number_characters(num: usize, out: &mut String) {
+	for (place, name) in [(1000, "千"), (100, "百"), (10, "十")] {
+		let d = num / place % 10;
+		if d > 1 { out.push_str(DIGIT_CHARACTERS[d]); }
+		if d > 0 { out.push_str(name); }
+	}
+	out.push_str(DIGIT_CHARACTERS[num % 10]);
+}
+
+// Writes a number below one myriad in romaji, as a single word. Some of the
+// hundreds and thousands change their sound, such as "sanbyaku" for 300.
+fn number_romaji(num: usize, out: &mut String) {
+	match num / 1000 {
+		0 => (),
+		3 => out.push_str("sanzen"),
+		8 => out.push_str("hassen"),
+		d => {
+			if d > 1 { out.push_str(DIGIT_ROMAJI[d]); }
+			out.push_str("sen");
+		}
+	}
+
+	match num / 100 % 10 {
+		0 => (),
+		3 => out.push_str("sanbyaku"),
+		6 => out.push_str("roppyaku"),
+		8 => out.push_str("happyaku"),
+		d => {
+			if d > 1 { out.push_str(DIGIT_ROMAJI[d]); }
+			out.push_str("hyaku");
+		}
+	}
+
+	let tens = num / 10 % 10;
+	if tens > 1 { out.push_str(DIGIT_ROMAJI[tens]); }
+	if tens > 0 { out.push_str("jū"); }
+	out.push_str(DIGIT_ROMAJI[num % 10]);
+}
+
+/// Gives the full length name of a number in an East Asian myriad system.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. If the number is
+///   too large for the chosen variant, `ParseError::InputTooLarge` is returned.
+/// * `variant` - How many times the last unit each unit is worth.
+/// * `script` - Whether the name is written in characters or in romaji.
+///
+/// # Example
+///
+/// ```
+/// use googology::east_asian::{Script, Variant, full_name};
+/// let characters = full_name("120000000", Variant::Myriad, Script::Characters).unwrap();
+/// let romaji = full_name("120000000", Variant::Myriad, Script::Romaji).unwrap();
+/// assert_eq!("一億二千万", characters.as_str());
+/// assert_eq!("ichi oku nisen man", romaji.as_str());
+/// ```
+pub fn full_name(digits: &str, variant: Variant, script: Script) -> Result<String, ParseError> {
+	let (negative, digits) = split_number(digits)?;
+	if digits.len() > max_digits(variant) { return Err(ParseError::InputTooLarge); }
+	if digits.is_empty() {
+		return Ok(String::from(match script { Script::Characters => "零", Script::Romaji => "rei" }));
+	}
+
+	let mut output = String::new();
+	match script {
+		Script::Characters => {
+			if negative { output.push_str("マイナス"); }
+			for term in terms(digits, variant) {
+				match term {
+					Term::Number(n) => number_characters(n, &mut output),
+					Term::Unit(u) => output.push_str(UNIT_CHARACTERS[u]),
+				}
+			}
+		}
+		Script::Romaji => {
+			if negative { output.push_str("mainasu "); }
+			for term in terms(digits, variant) {
+				match term {
+					Term::Number(n) => number_romaji(n, &mut output),
+					Term::Unit(u) => output.push_str(UNIT_ROMAJI[u]),
+				}
+				output.push(' ');
+			}
+			output.pop();
+		}
+	}
+
+	Ok(output)
+}
+
+/// Gives a name for a number representing a power of ten, such as "一京" for
+/// 10^16 in the modern system.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err. A negative exponent gives
+///   `ParseError::NegativeExponent`, and an exponent too large for the chosen
+///   variant gives `ParseError::InputTooLarge`.
+/// * `variant` - How many times the last unit each unit is worth.
+/// * `script` - Whether the name is written in characters or in romaji.
+///
+/// # Example
+///
+/// ```
+/// use googology::east_asian::{Script, Variant, power_of_ten};
+/// let modern = power_of_ten("68", Variant::Myriad, Script::Characters).unwrap();
+/// let upper = power_of_ten("32", Variant::Upper, Script::Romaji).unwrap();
+/// assert_eq!("一無量大数", modern.as_str());
+/// assert_eq!("ichi kei", upper.as_str());
+/// ```
+pub fn power_of_ten(digits: &str, variant: Variant, script: Script) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?
+		.to_usize()
+		.filter(|&p| p < max_digits(variant))
+		.ok_or(ParseError::InputTooLarge)?;
+
+	let digits = format!("1{}", "0".repeat(power));
+	full_name(digits.as_str(), variant, script)
+}
+
+impl NamingSystem for EastAsian {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits, self.variant, self.script)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits, self.variant, self.script)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn myriad_names() -> Result<(), ParseError> {
+		let chars = |digits| full_name(digits, Variant::Myriad, Script::Characters);
+		let romaji = |digits| full_name(digits, Variant::Myriad, Script::Romaji);
+		assert_eq!("零", chars("0")?);
+		assert_eq!("rei", romaji("-000")?);
+		assert_eq!("十", chars("10")?);
+		assert_eq!("二十一", chars("21")?);
+		assert_eq!("千百十一", chars("1111")?);
+		assert_eq!("一万", chars("10000")?);
+		assert_eq!("一億二千三百四十五万六千七百八十九", chars("123456789")?);
+		assert_eq!("一兆一", chars("1000000000001")?);
+		assert_eq!("マイナス四十二", chars("-42")?);
+
+		assert_eq!("yonjūni", romaji("42")?);
+		assert_eq!("sanbyaku roppyaku happyaku", format!(
+			"{} {} {}", romaji("300")?, romaji("600")?, romaji("800")?
+		));
+		assert_eq!("sanzen hassen", format!("{} {}", romaji("3000")?, romaji("8000")?));
+		assert_eq!("kyūsenkyūhyakukyūjūkyū", romaji("9999")?);
+		assert_eq!("ichi oku nisen man", romaji("120000000")?);
+		assert_eq!("mainasu nana chō", romaji("-7000000000000")?);
+
+		let largest = "9".repeat(72);
+		assert!(chars(largest.as_str())?.starts_with("九千九百九十九無量大数"));
+		let too_large = format!("1{}", "0".repeat(72));
+		assert_eq!(Err(ParseError::InputTooLarge), chars(too_large.as_str()));
+		assert_eq!(Err(ParseError::InvalidDigit), chars("1万"));
+		assert_eq!(Err(ParseError::Empty), chars(""));
+		Ok(())
+	}
+
+	#[test]
+	fn classical_names() -> Result<(), ParseError> {
+		let lower = |digits| full_name(digits, Variant::Lower, Script::Characters);
+		assert_eq!("一億", lower("100000")?);
+		assert_eq!("一億二万三千四百五十六", lower("123456")?);
+		assert_eq!("九載", lower("900000000000000")?);
+		assert_eq!(Err(ParseError::InputTooLarge), lower("1000000000000000"));
+
+		let middle = |digits| full_name(digits, Variant::Middle, Script::Characters);
+		assert_eq!("一万億", middle("1000000000000")?);
+		assert_eq!("一兆", middle("10000000000000000")?);
+		assert_eq!("十二兆三億", middle("120000000300000000")?);
+
+		let upper = |digits| full_name(digits, Variant::Upper, Script::Characters);
+		assert_eq!("一万億", upper("1000000000000")?);
+		let (oku_cho, kei) = (format!("1{}", "0".repeat(24)), format!("1{}", "0".repeat(32)));
+		let cho_kei_one = format!("1{}1", "0".repeat(47));
+		assert_eq!("一億兆", upper(oku_cho.as_str())?);
+		assert_eq!("一京", upper(kei.as_str())?);
+		assert_eq!("一兆京一", upper(cho_kei_one.as_str())?);
+		Ok(())
+	}
+
+	#[test]
+	fn powers_of_ten() -> Result<(), ParseError> {
+		assert_eq!("一", power_of_ten("0", Variant::Myriad, Script::Characters)?);
+		assert_eq!("百万", power_of_ten("6", Variant::Myriad, Script::Characters)?);
+		assert_eq!("ichi muryōtaisū", power_of_ten("68", Variant::Myriad, Script::Romaji)?);
+		assert_eq!("千無量大数", power_of_ten("71", Variant::Myriad, Script::Characters)?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("72", Variant::Myriad, Script::Characters));
+		assert_eq!("一載", power_of_ten("14", Variant::Lower, Script::Characters)?);
+		assert_eq!("一載", power_of_ten("80", Variant::Middle, Script::Characters)?);
+		assert_eq!("ichi sai", power_of_ten("4096", Variant::Upper, Script::Romaji)?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("8192", Variant::Upper, Script::Romaji));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-4", Variant::Myriad, Script::Romaji));
+
+		let system = EastAsian { variant: Variant::Middle, script: Script::Romaji };
+		assert_eq!("ichi chō", system.power_of_ten("16")?);
+		Ok(())
+	}
+}
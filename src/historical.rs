@@ -0,0 +1,284 @@
+//! Methods for naming numbers as they were written in earlier centuries, so
+//! that a number may be shown as it would have appeared in its own era. The
+//! small numbers are given in modern English, as elsewhere in this crate, but
+//! the names of large numbers keep the spellings of their sources.
+//!
+//! Three historical scales are provided:
+//! * `HistoricalScale::Chuquet` follows Nicolas Chuquet's manuscript
+//!   "Triparty en la science des nombres" (1484), the first known use of
+//!   names beyond the million. The digits are grouped by six, and each group
+//!   is followed by one of million, byllion, tryllion, quadrillion, quyllion,
+//!   sixlion, septyllion, ottyllion or nonyllion, the last of which Chuquet
+//!   gives. Numbers of 10^60 or more cannot be named.
+//! * `HistoricalScale::Peletier` follows Jacques Peletier du Mans'
+//!   "L'Arithmetique" (1549), which keeps Chuquet's byllion, but calls a
+//!   thousand millions a milliart rather than a thousand million. Only the
+//!   million, milliart and byllion are named, so numbers of 10^15 or more
+//!   cannot be named.
+//! * `HistoricalScale::FrenchShort` follows the French usage from the 17th
+//!   century until 1948, when France returned to the long scale. Each name is
+//!   1000 times the last, as in the modern short scale, so that a billion is a
+//!   thousand millions. Only the names defined in Littré's "Dictionnaire de la
+//!   langue française" (1873) are used, up to the trillion, so numbers of
+//!   10^15 or more cannot be named.
+
+use std::fmt;
+use num_traits::cast::ToPrimitive;
+
+use crate::common::{
+	name_string,
+	num_from_slice,
+	power_from_digits,
+	split_number,
+	write_myriad_words,
+	write_word
+};
+
+use crate::{NamingSystem, ParseError};
+
+// Chuquet's names for each power of a million, in his own spellings.
+static CHUQUET_NAMES: [&str; 9] = [
+	"million", "byllion", "tryllion", "quadrillion", "quyllion", "sixlion",
+	"septyllion", "ottyllion", "nonyllion"
+];
+
+// The names for each power of a thousand in the French short scale.
+static FRENCH_SHORT_NAMES: [&str; 4] = ["thousand", "million", "billion", "trillion"];
+
+/// A parameter for historical functions which indicates which era's names
+/// should be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoricalScale {
+	/// Nicolas Chuquet's scheme of 1484. 10^12 will be called one byllion, and
+	/// 10^9 will be called one thousand million.
+	Chuquet,
+	/// Jacques Peletier du Mans' scheme of 1549. 10^12 will be called one
+	/// byllion, and 10^9 will be called one milliart.
+	Peletier,
+	/// The short scale used in France before 1948. 10^9 will be called one
+	/// billion, and 10^12 will be called one trillion.
+	FrenchShort,
+}
+
+/// The historical scales as a `NamingSystem`, using the given scale for each
+/// of its names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Historical {
+	pub scale: HistoricalScale,
+}
+
+// Gives the most digits that a number may have in the given scale.
+fn max_digits(scale: HistoricalScale) -> usize {
+	match scale {
+		HistoricalScale::Chuquet => 6*(CHUQUET_NAMES.len() + 1),
+		// Peletier's names go no further than the byllion.
+		HistoricalScale::Peletier => 15,
+		HistoricalScale::FrenchShort => 3*(FRENCH_SHORT_NAMES.len() + 1),
+	}
+}
+
+/// Gives the full length name of a number in a historical scale.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. If the number is
+///   too large for the chosen scale, `ParseError::InputTooLarge` is returned.
+/// * `scale` - The historical source whose names are used.
+///
+/// # Example
+///
+/// ```
+/// use googology::historical::{HistoricalScale, full_name};
+/// let chuquet = full_name("2000000000000", HistoricalScale::Chuquet).unwrap();
+/// let french = full_name("2000000000000", HistoricalScale::FrenchShort).unwrap();
+/// assert_eq!("two byllion", chuquet.as_str());
+/// assert_eq!("two trillion", french.as_str());
+/// ```
+pub fn full_name(digits: &str, scale: HistoricalScale) -> Result<String, ParseError> {
+	name_string(|out| write_full_name(digits, scale, out))
+}
+
+/// Writes the full length name of a number in the same manner as `full_name`.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err without writing
+///   anything.
+/// * `scale` - The historical source whose names are used.
+/// * `out` - Any `fmt::Write`, such as a `String`, to write the name into.
+///
+/// # Example
+///
+/// ```
+/// use googology::historical::{HistoricalScale, write_full_name};
+/// let mut name = String::new();
+/// write_full_name("1500000000", HistoricalScale::Peletier, &mut name).unwrap();
+/// assert_eq!("one milliart five hundred million", name.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(
+	digits: &str,
+	scale: HistoricalScale,
+	out: &mut W
+) -> Result<(), ParseError> {
+	let (negative, digits) = split_number(digits)?;
+	if digits.is_empty() {
+		out.write_str("zero")?;
+		return Ok(());
+	}
+	if digits.len() > max_digits(scale) { return Err(ParseError::InputTooLarge); }
+
+	let mut first = true;
+	if negative { write_word("minus", &mut first, out)?; }
+
+	// The long scales are read six digits at a time, so that the thousands
+	// of each name come before it, as in "one thousand byllion".
+	let size = if scale == HistoricalScale::FrenchShort { 3 } else { 6 };
+	let groups = digits.len().div_ceil(size);
+	let mut start = 0;
+	for group in (0..groups).rev() {
+		let end = digits.len() - size*group;
+		let num = num_from_slice(digits, start, end - start);
+		start = end;
+
+		if num == 0 { continue; }
+		match (scale, group) {
+			(HistoricalScale::FrenchShort, _) => {
+				write_myriad_words(num, &mut first, out)?;
+				if group > 0 { write_word(FRENCH_SHORT_NAMES[group - 1], &mut first, out)?; }
+			}
+			(HistoricalScale::Peletier, 1) => {
+				// A thousand millions is given a name of its own.
+				if num >= 1000 {
+					write_myriad_words(num / 1000, &mut first, out)?;
+					write_word("milliart", &mut first, out)?;
+				}
+				if !num.is_multiple_of(1000) {
+					write_myriad_words(num % 1000, &mut first, out)?;
+					write_word("million", &mut first, out)?;
+				}
+			}
+			(_, _) => {
+				if num >= 1000 {
+					write_myriad_words(num / 1000, &mut first, out)?;
+					write_word("thousand", &mut first, out)?;
+				}
+				write_myriad_words(num % 1000, &mut first, out)?;
+				if group > 0 { write_word(CHUQUET_NAMES[group - 1], &mut first, out)?; }
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Gives a name for a number representing a power of ten in a historical
+/// scale.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err. A negative exponent gives
+///   `ParseError::NegativeExponent`, and an exponent too large for the chosen
+///   scale gives `ParseError::InputTooLarge`.
+/// * `scale` - The historical source whose names are used.
+///
+/// # Example
+///
+/// ```
+/// use googology::historical::{HistoricalScale, power_of_ten};
+/// let nonyllion = power_of_ten("54", HistoricalScale::Chuquet).unwrap();
+/// let trillion = power_of_ten("12", HistoricalScale::FrenchShort).unwrap();
+/// assert_eq!("one nonyllion", nonyllion.as_str());
+/// assert_eq!("one trillion", trillion.as_str());
+/// ```
+pub fn power_of_ten(digits: &str, scale: HistoricalScale) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?
+		.to_usize()
+		.filter(|&p| p < max_digits(scale))
+		.ok_or(ParseError::InputTooLarge)?;
+
+	let digits = format!("1{}", "0".repeat(power));
+	full_name(digits.as_str(), scale)
+}
+
+impl NamingSystem for Historical {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits, self.scale)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits, self.scale)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn chuquet_names() -> Result<(), ParseError> {
+		// Chuquet's own example from the Triparty (1484), which he reads as
+		// "745324 tryllions 804300 byllions 700023 millions 654321".
+		assert_eq!(
+			"seven hundred forty five thousand three hundred twenty four tryllion \
+			 eight hundred four thousand three hundred byllion \
+			 seven hundred thousand twenty three million \
+			 six hundred fifty four thousand three hundred twenty one",
+			full_name("745324804300700023654321", HistoricalScale::Chuquet)?
+		);
+
+		// The names of each power of a million, as listed in the Triparty.
+		let names = [
+			"million", "byllion", "tryllion", "quadrillion", "quyllion", "sixlion",
+			"septyllion", "ottyllion", "nonyllion"
+		];
+		for (k, name) in names.iter().enumerate() {
+			let power = (6*(k + 1)).to_string();
+			assert_eq!(format!("one {}", name), power_of_ten(power.as_str(), HistoricalScale::Chuquet)?);
+		}
+
+		assert_eq!("zero", full_name("-0", HistoricalScale::Chuquet)?);
+		assert_eq!("one thousand byllion", power_of_ten("15", HistoricalScale::Chuquet)?);
+		assert_eq!("minus one thousand one million", full_name("-1001000000", HistoricalScale::Chuquet)?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("60", HistoricalScale::Chuquet));
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("745,324", HistoricalScale::Chuquet));
+		assert_eq!(Err(ParseError::Empty), full_name("", HistoricalScale::Chuquet));
+		Ok(())
+	}
+
+	#[test]
+	fn peletier_names() -> Result<(), ParseError> {
+		// Peletier's L'Arithmetique (1549) names a thousand millions milliart,
+		// while keeping Chuquet's byllion for a million millions.
+		assert_eq!("one milliart", power_of_ten("9", HistoricalScale::Peletier)?);
+		assert_eq!("one byllion", power_of_ten("12", HistoricalScale::Peletier)?);
+		assert_eq!(
+			"three hundred byllion seven hundred milliart twenty three million \
+			 six hundred fifty four thousand three hundred twenty one",
+			full_name("300700023654321", HistoricalScale::Peletier)?
+		);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("15", HistoricalScale::Peletier));
+		Ok(())
+	}
+
+	#[test]
+	fn french_short_names() -> Result<(), ParseError> {
+		// Littré's Dictionnaire de la langue française (1873) defines the
+		// billion as "mille millions" and the trillion as "mille billions".
+		let system = Historical { scale: HistoricalScale::FrenchShort };
+		assert_eq!("one billion", system.power_of_ten("9")?);
+		assert_eq!("one trillion", system.power_of_ten("12")?);
+		assert_eq!(
+			"three hundred trillion seven hundred billion twenty three million \
+			 six hundred fifty four thousand three hundred twenty one",
+			system.full_name("300700023654321")?
+		);
+		assert_eq!(Err(ParseError::InputTooLarge), system.power_of_ten("15"));
+		Ok(())
+	}
+}
@@ -0,0 +1,307 @@
+//! Methods for the Indian numbering system, as used across South Asia.
+//! Rather than grouping digits by threes, only the last three digits are
+//! grouped together, and every two digits above them are given a new name.
+//! Thus, 1,23,45,678 is called "one crore twenty three lakh forty five
+//! thousand six hundred seventy eight".
+//!
+//! In everyday use, the names end with kharab (10^11), so that the largest
+//! number which can be named is just below 10^13. Two extensions are provided
+//! for numbers beyond this:
+//! * `Mode::CroreChaining` names every power of 10^7 with another "crore",
+//!   as is often done in finance and the press, so that 10^14 is called "one
+//!   crore crore". As with the names read by the `parse_name` functions,
+//!   numbers of more than 2^24 digits are refused.
+//! * `Mode::Traditional` continues the names with neel (10^13), padma (10^15)
+//!   and shankh (10^17), so that the largest number which can be named is just
+//!   below 10^19.
+
+use std::fmt;
+use num_traits::cast::ToPrimitive;
+
+use crate::common::{
+	MAX_PARSED_DIGITS,
+	name_string,
+	num_from_slice,
+	power_from_digits,
+	split_number,
+	write_myriad_words,
+	write_word
+};
+
+use crate::{NamingSystem, ParseError};
+
+// The names for each group of digits above the last three, starting from the
+// thousands. Which of these are used depends on the mode.
+static GROUP_NAMES: [&str; 8] = [
+	"thousand", "lakh", "crore", "arab", "kharab", "neel", "padma", "shankh"
+];
+
+// How many digits make up one crore in Mode::CroreChaining.
+const CRORE_DIGITS: usize = 7;
+
+/// A parameter for Indian functions which indicates how numbers beyond the
+/// everyday names are called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+	/// The names in everyday use, ending with kharab. Numbers of 10^13 or
+	/// more cannot be named.
+	Standard,
+	/// Only the names up to crore are used, and each further power of 10^7 is
+	/// given another "crore". 10^9 will be called one hundred crore, and
+	/// numbers of more than 2^24 digits cannot be named.
+	CroreChaining,
+	/// The everyday names are followed by neel, padma and shankh. Numbers of
+	/// 10^19 or more cannot be named.
+	Traditional,
+}
+
+/// The Indian numbering system as a `NamingSystem`, using the given mode for
+/// each of its names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indian {
+	pub mode: Mode,
+}
+
+// Gives the most digits that a number may have in the given mode.
+fn max_digits(mode: Mode) -> usize {
+	match mode {
+		Mode::Standard => 13,
+		Mode::CroreChaining => MAX_PARSED_DIGITS,
+		Mode::Traditional => 19,
+	}
+}
+
+// Writes the words for a number with no leading sign, grouping its digits
+// 3-2-2-2 from the right. Each group above the last three is followed by one
+// of the given names, and if there are more groups than names, this gives
+// ParseError::InputTooLarge without writing anything.
+fn write_groups<W: fmt::Write>(
+	digits: &str,
+	names: &[&str],
+	first: &mut bool,
+	out: &mut W
+) -> Result<(), ParseError> {
+	let groups = 1 + digits.len().saturating_sub(3).div_ceil(2);
+	if groups > names.len() + 1 { return Err(ParseError::InputTooLarge); }
+
+	// The most significant group may have fewer digits than the others.
+	let mut index = 0;
+	for group in (0..groups).rev() {
+		let end = digits.len() - if group == 0 { 0 } else { 3 + 2*(group - 1) };
+		let len = end - index;
+		let num = num_from_slice(digits, index, len);
+		index = end;
+
+		if num == 0 { continue; }
+		write_myriad_words(num, first, out)?;
+		if group > 0 { write_word(names[group - 1], first, out)?; }
+	}
+
+	Ok(())
+}
+
+// Writes the words for a number with no leading sign or zeroes, in the given
+// mode.
+fn write_digits<W: fmt::Write>(
+	digits: &str,
+	mode: Mode,
+	first: &mut bool,
+	out: &mut W
+) -> Result<(), ParseError> {
+	match mode {
+		Mode::Standard => write_groups(digits, &GROUP_NAMES[..5], first, out),
+		Mode::Traditional => write_groups(digits, &GROUP_NAMES, first, out),
+		Mode::CroreChaining => {
+			// Every seven digits are named as a number below one crore, followed
+			// by as many crores as there are groups of seven digits below them.
+			let chunks = digits.len().div_ceil(CRORE_DIGITS);
+			let mut index = 0;
+			for crores in (0..chunks).rev() {
+				let end = digits.len() - CRORE_DIGITS*crores;
+				let chunk = &digits[index..end];
+				index = end;
+
+				if chunk.bytes().all(|b| b == b'0') { continue; }
+				write_groups(chunk, &GROUP_NAMES[..2], first, out)?;
+				for _ in 0..crores { write_word("crore", first, out)?; }
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Gives the full length name of a number in the Indian numbering system.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. If the number is
+///   too large for the chosen mode, `ParseError::InputTooLarge` is returned.
+/// * `mode` - Which names are used for numbers beyond kharab.
+///
+/// # Example
+///
+/// ```
+/// use googology::indian::{Mode, full_name};
+/// let crore = full_name("34000000", Mode::Standard).unwrap();
+/// let chained = full_name("1200000000000000", Mode::CroreChaining).unwrap();
+/// assert_eq!("three crore forty lakh", crore.as_str());
+/// assert_eq!("twelve crore crore", chained.as_str());
+/// ```
+pub fn full_name(digits: &str, mode: Mode) -> Result<String, ParseError> {
+	name_string(|out| write_full_name(digits, mode, out))
+}
+
+/// Writes the full length name of a number in the same manner as `full_name`.
+/// Nothing is written if the number is too large for the chosen mode.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `mode` - Which names are used for numbers beyond kharab.
+/// * `out` - The writer to send the name to.
+///
+/// # Example
+///
+/// ```
+/// use googology::indian::{Mode, write_full_name};
+/// let mut name = String::new();
+/// write_full_name("1200000", Mode::Standard, &mut name).unwrap();
+/// assert_eq!("twelve lakh", name.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(
+	digits: &str,
+	mode: Mode,
+	out: &mut W
+) -> Result<(), ParseError> {
+	let (negative, digits) = split_number(digits)?;
+	if digits.is_empty() {
+		out.write_str("zero")?;
+		return Ok(());
+	}
+	if digits.len() > max_digits(mode) { return Err(ParseError::InputTooLarge); }
+
+	let mut first = true;
+	if negative { write_word("minus", &mut first, out)?; }
+	write_digits(digits, mode, &mut first, out)
+}
+
+/// Gives a name for a number representing a power of ten, such as "ten lakh"
+/// for 10^6.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err. A negative exponent gives
+///   `ParseError::NegativeExponent`, and an exponent too large for the chosen
+///   mode gives `ParseError::InputTooLarge`.
+/// * `mode` - Which names are used for powers beyond kharab.
+///
+/// # Example
+///
+/// ```
+/// use googology::indian::{Mode, power_of_ten};
+/// let shankh = power_of_ten("17", Mode::Traditional).unwrap();
+/// let crores = power_of_ten("23", Mode::CroreChaining).unwrap();
+/// assert_eq!("one shankh", shankh.as_str());
+/// assert_eq!("one hundred crore crore crore", crores.as_str());
+/// ```
+pub fn power_of_ten(digits: &str, mode: Mode) -> Result<String, ParseError> {
+	// The limit is checked before the digits of the power are written out.
+	let power = power_from_digits(digits)?
+		.to_usize()
+		.filter(|&p| p < max_digits(mode))
+		.ok_or(ParseError::InputTooLarge)?;
+
+	let digits = format!("1{}", "0".repeat(power));
+	full_name(digits.as_str(), mode)
+}
+
+impl NamingSystem for Indian {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits, self.mode)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits, self.mode)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn standard_names() -> Result<(), ParseError> {
+		assert_eq!("zero", full_name("000", Mode::Standard)?);
+		assert_eq!("zero", full_name("-0", Mode::Standard)?);
+		assert_eq!("forty two", full_name("42", Mode::Standard)?);
+		assert_eq!("nine hundred ninety nine", full_name("999", Mode::Standard)?);
+		assert_eq!("twelve lakh", full_name("1200000", Mode::Standard)?);
+		assert_eq!("one arab", full_name("1000000000", Mode::Standard)?);
+		assert_eq!("minus one lakh one", full_name("-100001", Mode::Standard)?);
+		assert_eq!(
+			"one crore twenty three lakh forty five thousand six hundred seventy eight",
+			full_name("12345678", Mode::Standard)?
+		);
+		assert_eq!(
+			"ninety nine kharab ninety nine arab ninety nine crore ninety nine lakh \
+			 ninety nine thousand nine hundred ninety nine",
+			full_name("9999999999999", Mode::Standard)?
+		);
+
+		assert_eq!(Err(ParseError::InputTooLarge), full_name("10000000000000", Mode::Standard));
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("1,00,000", Mode::Standard));
+		assert_eq!(Err(ParseError::Empty), full_name("", Mode::Standard));
+		Ok(())
+	}
+
+	#[test]
+	fn extended_names() -> Result<(), ParseError> {
+		let chained = |digits| full_name(digits, Mode::CroreChaining);
+		assert_eq!("one hundred crore", chained("1000000000")?);
+		assert_eq!("one lakh crore", chained("1000000000000")?);
+		assert_eq!("one crore crore", chained("100000000000000")?);
+		let digits = format!("5{}7", "0".repeat(20));
+		assert_eq!("five crore crore crore seven", chained(digits.as_str())?);
+		assert_eq!(
+			"twelve crore crore three thousand crore forty two",
+			chained("1200030000000042")?
+		);
+		let digits = format!("1{}", "0".repeat(700));
+		assert_eq!(format!("one{}", " crore".repeat(100)), chained(digits.as_str())?);
+		let too_large = format!("1{}", "0".repeat(1 << 24));
+		assert_eq!(Err(ParseError::InputTooLarge), chained(too_large.as_str()));
+
+		let traditional = |digits| full_name(digits, Mode::Traditional);
+		assert_eq!("one neel", traditional("10000000000000")?);
+		assert_eq!("twelve padma thirty four lakh", traditional("12000000003400000")?);
+		assert_eq!("ninety nine shankh", traditional("9900000000000000000")?);
+		assert_eq!(Err(ParseError::InputTooLarge), traditional("10000000000000000000"));
+		Ok(())
+	}
+
+	#[test]
+	fn powers_of_ten() -> Result<(), ParseError> {
+		assert_eq!("one", power_of_ten("0", Mode::Standard)?);
+		assert_eq!("ten lakh", power_of_ten("6", Mode::Standard)?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("13", Mode::Standard));
+		assert_eq!("ten padma", power_of_ten("16", Mode::Traditional)?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("19", Mode::Traditional));
+		assert_eq!("one crore crore", power_of_ten("14", Mode::CroreChaining)?);
+		assert_eq!("ten lakh crore", power_of_ten("13", Mode::CroreChaining)?);
+		let largest = power_of_ten("699", Mode::CroreChaining)?;
+		assert!(largest.starts_with("ten lakh crore crore"));
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("16777216", Mode::CroreChaining));
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("100000000000000", Mode::CroreChaining));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-3", Mode::Standard));
+
+		let system = Indian { mode: Mode::CroreChaining };
+		assert_eq!(system.full_name("10000000")?, system.power_of_ten("7")?);
+		Ok(())
+	}
+}
@@ -29,51 +29,96 @@
 //! the scale of "one duosexagintyllion" (10^(2^64)). If we were on a RISC-V
 //! 128-bit system with a maximum amount of RAM, the largest named number would
 //! be "one sesviginticentyllion" (10^(2^128)).
+//!
+//! The `write_` functions here send their names to a writer in the same way
+//! as those of the `conway_wechsler` module.
 
 extern crate num_traits;
 extern crate num_bigint;
 
-use std::str::FromStr;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io;
+use std::str::Bytes;
 use num_traits::cast::ToPrimitive;
 use num_traits::identities::Zero;
+use num_traits::identities::One;
 use num_bigint::BigUint;
 
 use crate::common::{
+	GroupWords,
+	MAX_PARSED_DIGITS,
+	NameParts,
+	YllionGroups,
 	is_all_digits,
-	num_from_slice,
 	latin_prefix,
-	myriad_number
+	latin_values,
+	latin_yllion,
+	name_string,
+	parse_myriad,
+	power_from_digits,
+	split_joined_words,
+	split_unsigned,
+	write_approximate,
+	write_decimal,
+	write_io,
+	write_name_from_iter,
+	write_name_from_reader,
+	write_ordinal,
+	write_reciprocal,
+	write_scientific,
+	write_signed_name
 };
 
-use crate::ParseError;
-
-// Create a name for an arbitrary grouping of four digits.
-// This function's behavior should not be considered perfectly equivalent to the
-// zillion_number function on the conway_wechsler module, because it is not
-// bijective. The number 12,0000,0042,0000 is given the full_name of
-// "twelve myriad myllion forty two myriad", indicating that the word "myriad"
-// is intended to be returned both for the grouping containing 42, as well as
-// the grouping containing 12.
-//
-// Note: This function also returns an integer to be compared against the
-// last_largest value in the full_name function.
-fn zyllion_number(num: usize) -> Result<(String, usize), ParseError> {
-	// The last grouping has no qualifier,
-	// and every other grouping is just "myriad".
-	if num == 0     { return Ok((String::from(""), 0)); }
-	if num % 2 == 1 { return Ok((String::from("myriad"), 1)); }
-
-	// For the rest, we want to find the greatest power of 2 that we're a
-	// multiple of, convert it into a latin prefix, and add "yllion".
-	// Note that the greatest power of two should be in the range [1,63]
-	// by necessity, since num is an even-valued usize.
-	let mut name = String::from("");
-	let greatest_power_of_two = num.trailing_zeros() as usize;
-	let prefix = latin_prefix(greatest_power_of_two)?;
-
-	name.push_str(prefix.as_str());
-	name.push_str("yllion");
-	Ok((name, greatest_power_of_two + 1))
+use crate::{FractionStyle, MantissaStyle, NamingSystem, ParseError, Rounding, Style, Word};
+
+/// The Knuth -yllion system as a `NamingSystem`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Knuth;
+
+impl NameParts for Knuth {
+	type Groups = YllionGroups;
+
+	const UNIT_DIGITS: usize = 4;
+
+	fn groups(&self) -> YllionGroups {
+		YllionGroups::default()
+	}
+
+	fn write_power<W: fmt::Write>(&self, power: BigUint, out: &mut W) -> Result<(), ParseError> {
+		write_power(power, out)
+	}
+
+	fn begins_with_ten(&self, power: &BigUint) -> bool {
+		(power % 2u32).is_one()
+	}
+
+	// The unit is named as its power of ten would be, without the "one".
+	fn write_unit<W: fmt::Write>(&self, unit: usize, out: &mut W) -> Result<(), ParseError> {
+		if unit > 0 {
+			let mut name = String::new();
+			write_power(BigUint::from(unit) * 4u32, &mut name)?;
+			out.write_char(' ')?;
+			out.write_str(name.trim_start_matches("one "))?;
+		}
+		Ok(())
+	}
+}
+
+/// An iterator over each word in the full name of a number, as given by
+/// the `words` function.
+pub struct Words<'a> {
+	inner: GroupWords<Bytes<'a>, YllionGroups>,
+}
+
+impl Iterator for Words<'_> {
+	type Item = Word;
+
+	// The digits have already been checked, so the words cannot fail.
+	fn next(&mut self) -> Option<Word> {
+		self.inner.next().and_then(Result::ok)
+	}
 }
 
 /// Gives a full length name for a number represented by an arbitrary sequence
@@ -82,8 +127,9 @@ fn zyllion_number(num: usize) -> Result<(String, usize), ParseError> {
 /// # Arguments
 /// 
 /// * `digits` - A string slice that holds a representation of the number
-/// using only the digits 0-9. If any other character is present, this function
-/// will return an Err.
+///   using only the digits 0-9, optionally preceded by a sign. A negative
+///   number is prefixed with "minus". If any other character is present, this
+///   function will return an Err.
 /// 
 /// # Example
 /// 
@@ -94,102 +140,335 @@ fn zyllion_number(num: usize) -> Result<(String, usize), ParseError> {
 /// assert_eq!(name.as_str(), expected);
 /// ```
 pub fn full_name(digits: &str) -> Result<String, ParseError> {
-	// Sanity checks. We want the string to be entirely digits, and we want
-	// to handle the case of leading zeroes. If all digits are zero, we want
-	// to just return the string "zero", and otherwise process from the
-	// first nonzero character.
-	let first_nonzero = is_all_digits(digits)
-		.then(|| digits)
-		.ok_or(ParseError::InvalidDigit)
-		.and_then(|d|
-			if d.is_empty() { Err(ParseError::Empty) }
-			else { Ok(d.find(|c| c != '0')) }
-		)?;
+	name_string(|out| write_full_name(digits, out))
+}
 
-	let (mut i, mut output) = first_nonzero.map_or_else(
-		|| (0, String::from("zero")),
-		|idx| (idx, String::from(""))
-	);
-
-	if !output.is_empty() { return Ok(output); }
-
-	// Because each term zyllion term describes the quantity of the next
-	// largest term (i.e. one myriad myllion), we keep track of the most
-	// recent largest term we've outputted.
-	let mut last_largest : usize = 0;
-
-	// Determine number of digits to process, and how many digits are in
-	// the first grouping.
-	let mut remaining = digits.len() - i;
-	let first = remaining % 4;
-
-	if first > 0 {
-		let num     = num_from_slice(digits, i, first);
-		let leading = myriad_number(num)?;
-		let (zyllion, largest) = zyllion_number(remaining / 4)?;
-
-		output.push_str(leading.as_str());
-		if !zyllion.is_empty() {
-			output.push(' ');
-			output.push_str(zyllion.as_str());
-			last_largest = largest;
-		}
+/// Gives the full length name of a number that is already held as an integer,
+/// such as a `u64`, a `u128` or a `BigUint`. The number is written out in
+/// base 10 and named in the same manner as `write_full_name_from_iter`, so its
+/// digits are still read one at a time, but can never be rejected.
+///
+/// # Arguments
+/// 
+/// * `num` - The number to name. Anything that converts into a `BigUint` may
+///   be given.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::full_name_of;
+/// let name = full_name_of(1_0000_0042u64).unwrap();
+/// assert_eq!("one myllion forty two", name.as_str());
+/// ```
+pub fn full_name_of<T: Into<BigUint>>(num: T) -> Result<String, ParseError> {
+	let digits = num.into().to_str_radix(10);
+	name_string(|out| write_full_name_from_iter(digits.bytes(), digits.len(), out))
+}
 
-		remaining -= first;
-		i += first;
-	}
+/// Gives each word in the full length name of a number in turn, so that the
+/// name may be styled or inspected without reading the text of `full_name`
+/// back in. Each word is only made once the words before it have been taken.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::Word;
+/// use googology::knuth_yllion::words;
+/// let words = words("42000000000000").unwrap().collect::<Vec<Word>>();
+/// let expected = vec![
+///     Word::Small("forty"),
+///     Word::Small("two"),
+///     Word::Myriad,
+///     Word::Yllion(1),
+/// ];
+/// assert_eq!(expected, words);
+/// ```
+pub fn words(digits: &str) -> Result<Words<'_>, ParseError> {
+	let digits = split_unsigned(digits)?;
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	let inner = Knuth.words(digits.bytes(), digits.len())?;
+	Ok(Words { inner })
+}
 
-	// Handle the rest of the digits in chunks of four at a time.
-	while remaining > 0 {
-		let num     = num_from_slice(digits, i, 4);
-		let leading = myriad_number(num)?;
-		let (zyllion, largest) = zyllion_number((remaining - 1) / 4)?;
+/// Writes a full length name for a number represented by an arbitrary sequence
+/// of digits, in the same manner as `full_name`. Each word is sent to the
+/// writer as soon as it is made, rather than building the whole name in memory.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::write_full_name;
+/// let mut name = String::new();
+/// write_full_name("12000000420000", &mut name).unwrap();
+/// assert_eq!("twelve myriad myllion forty two myriad", name.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(digits: &str, out: &mut W) -> Result<(), ParseError> {
+	write_signed_full_name(digits, "minus", out)
+}
 
-		if !leading.is_empty() {
-			if !output.is_empty() { output.push(' '); }
+/// Gives the full length name of a number that may be negative, in the same
+/// manner as `full_name`, but with a choice of word for the sign.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `negative` - The word to put before the name of a negative number, such
+///   as "minus" or "negative". Zero is never given this word.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::signed_full_name;
+/// let name = signed_full_name("-420000", "negative").unwrap();
+/// assert_eq!("negative forty two myriad", name.as_str());
+/// ```
+pub fn signed_full_name(digits: &str, negative: &str) -> Result<String, ParseError> {
+	name_string(|out| write_signed_full_name(digits, negative, out))
+}
 
-			output.push_str(leading.as_str());
-			if !zyllion.is_empty() {
-				output.push(' ');
-				output.push_str(zyllion.as_str());
-				last_largest = largest;
-			}
-		}
+/// Writes the full length name of a number that may be negative, in the same
+/// manner as `signed_full_name`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::write_signed_full_name;
+/// let mut name = String::new();
+/// write_signed_full_name("-7", "minus", &mut name).unwrap();
+/// assert_eq!("minus seven", name.as_str());
+/// ```
+pub fn write_signed_full_name<W: fmt::Write>(
+	digits: &str,
+	negative: &str,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_signed_name(&Knuth, digits, negative, &Style::default(), out)
+}
 
-		// This condition does not trigger if we wrote a zyllion in the
-		// above block of code. Instead, it means that we have a group
-		// of all zeroes, but should be writing a zyllion that is larger
-		// than the last one that we wrote.
-		if largest > last_largest {
-			output.push(' ');
-			output.push_str(zyllion.as_str());
-			last_largest = largest;
-		}
+/// Gives the full length name of a number in the same manner as `full_name`,
+/// but with its words joined as given by a style. The default style gives the
+/// same name as `full_name`.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `style` - Options for hyphens, "and", and the separator between groups.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::{Separator, Style};
+/// use googology::knuth_yllion::full_name_with;
+/// let style = Style {
+///     hyphenate: true,
+///     and: true,
+///     separator: Separator::Comma,
+///     ..Style::default()
+/// };
+/// let name = full_name_with("4200420042", &style).unwrap();
+/// assert_eq!("forty-two myllion, forty-two myriad and forty-two", name.as_str());
+/// ```
+pub fn full_name_with(digits: &str, style: &Style) -> Result<String, ParseError> {
+	name_string(|out| write_full_name_with(digits, style, out))
+}
 
-		i += 4;
-		remaining -= 4;
-	}
-
-	Ok(output)
-}
-
-// A helper function for power_of_ten which handles the extremely large yllions.
-// According to Knuth's essay, for large enough n, 10^(2^(n+2)) shall be wrapped
-// in a name such as "latin{word for n with spaces removed}yllion". For us, this
-// value of n starts at 1000, yielding 10^(2^1002) as "latintenhundredyllion".
-// Although this system hypothetically allows for further recursion as in the
-// string "latinlatinlatinbyllionyllionyllionyllion", we do not support this
-// level of recursion at this time.
-fn latin_yllion(n: usize) -> String {
-	let nstr = n.to_string();
-	
-	full_name(nstr.as_str())
-		.unwrap_or_default()
-		.split_whitespace()
-		.fold(String::from("latin"), |mut s, w| {
-			s.push_str(w);
-			s
-		})
+/// Writes the full length name of a number in the same manner as
+/// `full_name_with`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::Style;
+/// use googology::knuth_yllion::write_full_name_with;
+/// let style = Style { hyphenate: true, ..Style::default() };
+/// let mut name = String::new();
+/// write_full_name_with("99", &style, &mut name).unwrap();
+/// assert_eq!("ninety-nine", name.as_str());
+/// ```
+pub fn write_full_name_with<W: fmt::Write>(
+	digits: &str,
+	style: &Style,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_signed_name(&Knuth, digits, "minus", style, out)
+}
+
+/// Writes a full length name for a number whose digits come from an iterator,
+/// in the same manner as `write_full_name`. This allows for a number to be
+/// named without ever holding all of its digits in memory at once.
+///
+/// # Arguments
+/// 
+/// * `digits` - An iterator over the ASCII digits of the number, from most
+///   to least significant. As these are only checked as they are read, if any
+///   other character is found, this function may return an Err after some
+///   of the name has already been written.
+/// * `len` - How many digits the iterator will give. This is needed to know
+///   which yllion the first digits belong to. If the iterator gives any more
+///   or any less digits, `ParseError::LengthMismatch` is returned.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::write_full_name_from_iter;
+/// let digits = std::iter::once(b'7').chain(std::iter::repeat(b'0').take(16));
+/// let mut name = String::new();
+/// write_full_name_from_iter(digits, 17, &mut name).unwrap();
+/// assert_eq!("seven byllion", name.as_str());
+/// ```
+pub fn write_full_name_from_iter<I, W>(
+	digits: I,
+	len: usize,
+	out: &mut W
+) -> Result<(), ParseError>
+where
+	I: IntoIterator<Item = u8>,
+	W: fmt::Write
+{
+	write_name_from_iter(&Knuth, digits.into_iter(), len, out)
+}
+
+/// Writes a full length name for a number whose digits are read from a
+/// seekable source such as a file, to an `io::Write`. The digits are read
+/// twice: once to count and check them, and then again as they are named,
+/// so the number never needs to be held in memory all at once.
+///
+/// Reading begins at the current position of the reader, and continues up to
+/// the end, so any trailing newline must not be present. If the digits cannot
+/// be given a name, the `ParseError` is returned inside an `io::Error` of the
+/// kind `io::ErrorKind::InvalidInput`. 
+/// 
+/// # Example
+/// 
+/// ```
+/// use std::io::Cursor;
+/// use googology::knuth_yllion::write_full_name_from_reader;
+/// let mut digits = Cursor::new("1200426208");
+/// let mut bytes = Vec::new();
+/// write_full_name_from_reader(&mut digits, &mut bytes).unwrap();
+/// let expected = "twelve myllion forty two myriad sixty two hundred eight";
+/// assert_eq!(expected.as_bytes(), bytes.as_slice());
+/// ```
+pub fn write_full_name_from_reader<R, W>(reader: &mut R, out: &mut W) -> io::Result<()>
+where
+	R: io::Read + io::Seek,
+	W: io::Write
+{
+	write_name_from_reader(&Knuth, reader, out)
+}
+
+/// Writes a full length name for a number in the same manner as
+/// `write_full_name`, but to an `io::Write` such as a file. As each word is
+/// written separately, an unbuffered writer should be wrapped in an
+/// `io::BufWriter` first.
+///
+/// If the digits cannot be given a name, the `ParseError` is returned inside
+/// an `io::Error` of the kind `io::ErrorKind::InvalidInput`. 
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::write_full_name_io;
+/// let mut bytes = Vec::new();
+/// write_full_name_io("100000000", &mut bytes).unwrap();
+/// assert_eq!(b"one myllion", bytes.as_slice());
+/// ```
+pub fn write_full_name_io<W: io::Write>(digits: &str, out: &mut W) -> io::Result<()> {
+	write_io(out, |w| write_full_name(digits, w))
+}
+
+/// Gives the ordinal name of a number, such as "twelfth" or "one myllionth".
+/// This is the same as `full_name`, with only the last word changed.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::ordinal_name;
+/// let myllionth = ordinal_name("100000000").unwrap();
+/// let twelfth = ordinal_name("12").unwrap();
+/// assert_eq!("one myllionth", myllionth.as_str());
+/// assert_eq!("twelfth", twelfth.as_str());
+/// ```
+pub fn ordinal_name(digits: &str) -> Result<String, ParseError> {
+	name_string(|out| write_ordinal_name(digits, out))
+}
+
+/// Writes the ordinal name of a number, in the same manner as `ordinal_name`.
+/// Every word but the last is sent to the writer as soon as it is made.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::write_ordinal_name;
+/// let mut name = String::new();
+/// write_ordinal_name("30000", &mut name).unwrap();
+/// assert_eq!("three myriadth", name.as_str());
+/// ```
+pub fn write_ordinal_name<W: fmt::Write>(digits: &str, out: &mut W) -> Result<(), ParseError> {
+	write_ordinal(&Knuth, digits, out)
+}
+
+/// Gives a name for a number which may have digits after a decimal point, such
+/// as "12.5" or "0.000001". A negative number is prefixed with "minus".
+///
+/// # Arguments
+/// 
+/// * `number` - A string slice that holds a representation of the number
+///   using only the digits 0-9, with an optional sign and decimal point. If
+///   any other character is present, this function will return an Err.
+/// * `style` - Whether the digits after the decimal point are named one by
+///   one, or as a fraction.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::FractionStyle;
+/// use googology::knuth_yllion::decimal_name;
+/// let pi = decimal_name("3.14", FractionStyle::Digits).unwrap();
+/// let small = decimal_name("0.0001", FractionStyle::Units).unwrap();
+/// assert_eq!("three point one four", pi.as_str());
+/// assert_eq!("one myriadth", small.as_str());
+/// ```
+pub fn decimal_name(number: &str, style: FractionStyle) -> Result<String, ParseError> {
+	name_string(|out| write_decimal_name(number, style, out))
+}
+
+/// Writes a name for a number which may have digits after a decimal point, in
+/// the same manner as `decimal_name`.
+///
+/// # Example
+/// 
+/// ```
+/// use googology::FractionStyle;
+/// use googology::knuth_yllion::write_decimal_name;
+/// let mut name = String::new();
+/// write_decimal_name("12.005", FractionStyle::Units, &mut name).unwrap();
+/// assert_eq!("twelve and five ten hundredths", name.as_str());
+/// ```
+pub fn write_decimal_name<W: fmt::Write>(
+	number: &str,
+	style: FractionStyle,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_decimal(&Knuth, number, style, out)
 }
 
 /// Gives a name for a number representing a power of ten.
@@ -204,8 +483,9 @@ fn latin_yllion(n: usize) -> String {
 /// # Arguments
 /// 
 /// * `digits` - A string slice that holds a representation of the number
-/// using only the digits 0-9. If any other character is present, this function
-/// will return an Err.
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. A negative
+///   exponent gives `ParseError::NegativeExponent`.
 /// 
 /// # Example
 ///
@@ -215,35 +495,62 @@ fn latin_yllion(n: usize) -> String {
 /// assert_eq!("one hundred myllion", one_hundred_myllion.as_str());
 /// ```
 pub fn power_of_ten(digits: &str) -> Result<String, ParseError> {
-	// Sanity check. We want to convert our input string into a Bignum.
-	// The num_bigint crate doesn't quite allow us to know the cause of
-	// error, but from what we can tell, it's either an invalid digit or
-	// an empty string. So we'll make this clear in our own error.
-	let mut power = is_all_digits(digits)
-		.then(|| digits)
-		.ok_or(ParseError::InvalidDigit)
-		.and_then(|d| 
-			if d.is_empty() { Err(ParseError::Empty) }
-			else { Ok(d) }
-		)
-		.and_then(|d| BigUint::from_str(d).map_err(|_| ParseError::InternalError))?;
+	name_string(|out| write_power_of_ten(digits, out))
+}
+
+/// Gives a name for a power of ten whose exponent is already held as a
+/// `BigUint`, skipping the checks made by `power_of_ten`.
+///
+/// # Arguments
+/// 
+/// * `power` - The power of ten to name.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::BigUint;
+/// use googology::knuth_yllion::power_of_ten_of;
+/// let name = power_of_ten_of(&BigUint::from(8u32)).unwrap();
+/// assert_eq!("one myllion", name.as_str());
+/// ```
+pub fn power_of_ten_of(power: &BigUint) -> Result<String, ParseError> {
+	name_string(|out| write_power(power.clone(), out))
+}
 
+/// Writes a name for a number representing a power of ten, in the same manner
+/// as `power_of_ten`. Each word is sent to the writer as soon as it is made,
+/// rather than building the whole name in memory.
+///
+/// # Example
+///
+/// ```
+/// use googology::knuth_yllion::write_power_of_ten;
+/// let mut name = String::new();
+/// write_power_of_ten("42", &mut name).unwrap();
+/// assert_eq!("one hundred myllion tryllion", name.as_str());
+/// ```
+pub fn write_power_of_ten<W: fmt::Write>(digits: &str, out: &mut W) -> Result<(), ParseError> {
+	let power = power_from_digits(digits)?;
+	write_power(power, out)
+}
 
+// Writes the name for a power of ten, once its digits have been read.
+fn write_power<W: fmt::Write>(mut power: BigUint, out: &mut W) -> Result<(), ParseError> {
 	// Consider small cases
 	let s = (&power % 2u32)
 		.to_u32()
 		.map(|m| match m { 0 => "one", 1 => "ten", _ => "" })
 		.unwrap_or("");
 
-	let mut output = String::from(s);
+	out.write_str(s)?;
 
 	power /= 2u32;
 	let m = (&power % 2u32).to_u32();
-	if m == Some(1) { output.push_str(" hundred"); }
+	if m == Some(1) { out.write_str(" hundred")?; }
 
 	power /= 2u32;
 	let m = (&power % 2u32).to_u32();
-	if m == Some(1) { output.push_str(" myriad"); }
+	if m == Some(1) { out.write_str(" myriad")?; }
 
 	// Break down the power one bit at a time, each time adding a new term.
 	let mut zyl_num = 1;
@@ -255,21 +562,400 @@ pub fn power_of_ten(digits: &str) -> Result<String, ParseError> {
 			let prefix = if zyl_num > 999 { latin_yllion(zyl_num) }
 			else { latin_prefix(zyl_num)? };
 
-			output.push(' ');
-			output.push_str(prefix.as_str());
-			output.push_str("yllion");
+			out.write_char(' ')?;
+			out.write_str(prefix.as_str())?;
+			out.write_str("yllion")?;
 		}
 
 		zyl_num += 1;		
 	}
 
-	Ok(output)
+	Ok(())
+}
+
+/// Writes a name for a number representing a power of ten in the same manner
+/// as `write_power_of_ten`, but to an `io::Write` such as a file.
+///
+/// If the digits cannot be given a name, the `ParseError` is returned inside
+/// an `io::Error` of the kind `io::ErrorKind::InvalidInput`. 
+pub fn write_power_of_ten_io<W: io::Write>(digits: &str, out: &mut W) -> io::Result<()> {
+	write_io(out, |w| write_power_of_ten(digits, w))
+}
+
+/// Gives a name for the reciprocal of a power of ten, such as "one millionth"
+/// for 10^-6. The exponent is given without its minus sign, and may be just
+/// as large as those given to `power_of_ten`.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9, without a sign. If any other character is
+///   present, this function will return an Err.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::knuth_yllion::reciprocal_power_of_ten;
+/// let myllionth = reciprocal_power_of_ten("8").unwrap();
+/// let tenth = reciprocal_power_of_ten("1").unwrap();
+/// assert_eq!("one myllionth", myllionth.as_str());
+/// assert_eq!("one tenth", tenth.as_str());
+/// ```
+pub fn reciprocal_power_of_ten(digits: &str) -> Result<String, ParseError> {
+	name_string(|out| write_reciprocal_power_of_ten(digits, out))
+}
+
+/// Writes a name for the reciprocal of a power of ten, in the same manner as
+/// `reciprocal_power_of_ten`. Only the last word is held back while writing.
+///
+/// # Example
+///
+/// ```
+/// use googology::knuth_yllion::write_reciprocal_power_of_ten;
+/// let mut name = String::new();
+/// write_reciprocal_power_of_ten("14", &mut name).unwrap();
+/// assert_eq!("one hundred myriad myllionth", name.as_str());
+/// ```
+pub fn write_reciprocal_power_of_ten<W: fmt::Write>(
+	digits: &str,
+	out: &mut W
+) -> Result<(), ParseError> {
+	// The minus sign is implied, so no sign may be given.
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	let power = power_from_digits(digits)?;
+	write_reciprocal(&Knuth, power, out)
+}
+
+/// Gives a name for a number written in scientific notation, such as "6.022e23"
+/// or "1.5e-9". If the number can be written out in full using at most
+/// `max_digits` digits, it is named exactly, as by `decimal_name`. Otherwise
+/// the mantissa is named digit by digit, followed by "times" and the name of
+/// its power of ten, so that even enormous exponents may be named.
+///
+/// # Arguments
+/// 
+/// * `number` - A string slice that holds a decimal mantissa, optionally
+///   followed by "e" or "E" and an exponent. Both may have a sign. If any other
+///   character is present, this function will return an Err.
+/// * `max_digits` - The largest number of digits that a number will be
+///   written out in full with.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::knuth_yllion::scientific_name;
+/// let exact = scientific_name("4.2e9", 100).unwrap();
+/// let huge = scientific_name("4.2e1000", 100).unwrap();
+/// assert_eq!("forty two myllion", exact.as_str());
+/// assert_eq!(
+///     "four point two times one myllion tryllion quadryllion quintyllion sextyllion septyllion",
+///     huge.as_str()
+/// );
+/// ```
+pub fn scientific_name(number: &str, max_digits: usize) -> Result<String, ParseError> {
+	name_string(|out| write_scientific_name(number, max_digits, out))
+}
+
+/// Writes a name for a number written in scientific notation, in the same
+/// manner as `scientific_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::knuth_yllion::write_scientific_name;
+/// let mut name = String::new();
+/// write_scientific_name("25e-5", 10, &mut name).unwrap();
+/// assert_eq!("zero point zero zero zero two five", name.as_str());
+/// ```
+pub fn write_scientific_name<W: fmt::Write>(
+	number: &str,
+	max_digits: usize,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_scientific(&Knuth, number, max_digits, out)
+}
+
+/// Gives a short, rounded name for a number, as a count of the largest
+/// power of one myriad, such as a myriad or a myllion. If any digits are lost
+/// to rounding, the name begins with "about". Only the first few digits are
+/// read closely, so this is quick even for numbers whose full names would be
+/// very long.
+///
+/// # Arguments
+/// 
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. A number below
+///   zero gives `ParseError::NegativeNumber`, and if any other character is
+///   present, this function will return an Err.
+/// * `sig_figs` - How many significant figures to keep. At least one is
+///   always kept.
+/// * `rounding` - How the digits past the significant figures are rounded.
+/// * `style` - Whether the count of the unit is written with digits or words.
+/// 
+/// # Example
+///
+/// ```
+/// use googology::{MantissaStyle, Rounding};
+/// use googology::knuth_yllion::approximate_name;
+/// let name = approximate_name("123456789012", 2, Rounding::HalfUp, MantissaStyle::Words);
+/// assert_eq!("about twelve hundred myllion", name.unwrap().as_str());
+/// ```
+pub fn approximate_name(
+	digits: &str,
+	sig_figs: usize,
+	rounding: Rounding,
+	style: MantissaStyle
+) -> Result<String, ParseError> {
+	name_string(|out| write_approximate_name(digits, sig_figs, rounding, style, out))
+}
+
+/// Writes a short, rounded name for a number, in the same manner as
+/// `approximate_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::{MantissaStyle, Rounding};
+/// use googology::knuth_yllion::write_approximate_name;
+/// let mut name = String::new();
+/// write_approximate_name("250000", 3, Rounding::Down, MantissaStyle::Digits, &mut name).unwrap();
+/// assert_eq!("25 myriad", name.as_str());
+/// ```
+pub fn write_approximate_name<W: fmt::Write>(
+	digits: &str,
+	sig_figs: usize,
+	rounding: Rounding,
+	style: MantissaStyle,
+	out: &mut W
+) -> Result<(), ParseError> {
+	write_approximate(&Knuth, digits, sig_figs, rounding, style, out)
+}
+
+
+// A piece of a name being read by parse_name. This is either a number in the
+// range [1, 9999], or a "myriad" or "-yllion" word given as the value of n
+// for which it is equal to 10^(2^n).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Term {
+	Number(usize),
+	Yllion(usize),
+}
+
+// The reverse of zyllion_number and latin_yllion. Reads a single word such as
+// "myllion" or "latintenhundredyllion", giving back the value of n for which
+// the word is equal to 10^(2^n).
+fn parse_yllion(word: &str, latin: &HashMap<String, usize>) -> Result<usize, ParseError> {
+	if word == "myriad" { return Ok(2); }
+
+	let stem = word.strip_suffix("yllion").ok_or(ParseError::UnknownWord)?;
+	let inner = match stem.strip_prefix("latin") {
+		Some(inner) => inner,
+		None => {
+			// There is no "nyllion", as that would be 10^4 (one myriad).
+			return match latin.get(stem) {
+				Some(&0) => Err(ParseError::InvalidName),
+				Some(&n) => Ok(n + 2),
+				None => Err(ParseError::UnknownWord),
+			};
+		}
+	};
+
+	// Latin yllions are only used once there are no more latin prefixes,
+	// and do not nest inside of one another.
+	let is_word = |w: &str| w != word && parse_yllion(w, latin).is_ok();
+	let words = split_joined_words(inner, &is_word)
+		.ok_or(ParseError::UnknownWord)?;
+	let mut terms = parse_terms(words.as_slice(), latin)?;
+
+	// Knuth's essay leaves out the leading "one", as in "latinmyriadyllion",
+	// although latin_yllion does not.
+	if let Some(Term::Yllion(_)) = terms.first() {
+		terms.insert(0, Term::Number(1));
+	}
+
+	let n = digits_from_terms(terms.as_slice())?
+		.parse::<usize>()
+		.map_err(|_| ParseError::InputTooLarge)?;
+
+	if n < 1000 { return Err(ParseError::InvalidName); }
+	n.checked_add(2).ok_or(ParseError::InputTooLarge)
+}
+
+// Breaks a name down into its numbers and yllions.
+fn parse_terms(words: &[&str], latin: &HashMap<String, usize>) -> Result<Vec<Term>, ParseError> {
+	let mut terms = Vec::new();
+	let mut i = 0;
+	while i < words.len() {
+		let (num, used) = parse_myriad(&words[i..])?;
+		if num > 0 {
+			terms.push(Term::Number(num));
+			i += used;
+		}
+		else if words[i] == "zero" {
+			return Err(ParseError::InvalidName);
+		}
+		else {
+			terms.push(Term::Yllion(parse_yllion(words[i], latin)?));
+			i += 1;
+		}
+	}
+	Ok(terms)
+}
+
+// Gives back the digits of the number described by some sequence of terms.
+// Every name is either a single number in the range [1, 9999], or is made up of
+// some smaller name, followed by its largest yllion, and then possibly another
+// smaller name. This is the same nesting followed by last_largest in full_name,
+// so that "myriad myllion" is read as a single multiplier.
+fn digits_from_terms(terms: &[Term]) -> Result<String, ParseError> {
+	let largest = terms
+		.iter()
+		.enumerate()
+		.filter_map(|(i, t)| match t {
+			Term::Yllion(n) => Some((i, *n)),
+			Term::Number(_) => None,
+		})
+		.max_by_key(|&(_, n)| n);
+
+	let (i, n) = match (largest, terms) {
+		(Some(largest), _) => largest,
+		(None, [Term::Number(num)]) => return Ok(num.to_string()),
+		(None, _) => return Err(ParseError::InvalidName),
+	};
+
+	// Each yllion may only be used once, with a smaller name before it.
+	let (before, after) = (&terms[..i], &terms[i+1..]);
+	if before.is_empty() || terms.iter().filter(|&&t| t == Term::Yllion(n)).count() > 1 {
+		return Err(ParseError::InvalidName);
+	}
+
+	// The digits before the yllion are fewer than those after it, so capping
+	// the width at half of the limit keeps the whole number within it. This
+	// is checked before anything is allocated.
+	let width = u32::try_from(n)
+		.ok()
+		.and_then(|n| 1usize.checked_shl(n))
+		.filter(|&w| w <= MAX_PARSED_DIGITS / 2)
+		.ok_or(ParseError::InputTooLarge)?;
+
+	let mut digits = digits_from_terms(before)?;
+	let rest = if after.is_empty() { String::from("") }
+	else { digits_from_terms(after)? };
+
+	digits.push_str("0".repeat(width - rest.len()).as_str());
+	digits.push_str(rest.as_str());
+	Ok(digits)
+}
+
+// Gives the error for a word found where no such word could be in a name, in
+// the same manner as the conway_wechsler module.
+fn unexpected_word(word: &str, latin: &HashMap<String, usize>) -> ParseError {
+	let known = ["zero", "hundred"].contains(&word)
+		|| parse_terms(&[word], latin).is_ok();
+
+	if known { ParseError::InvalidName } else { ParseError::UnknownWord }
+}
+
+/// Reads a name given by `full_name`, and gives back the digits of the number
+/// that it describes. This is the reverse of `full_name`, and gives back the
+/// digits without any leading zeroes. The "latin" yllions given by
+/// `power_of_ten` may also be read, although a name using them will likely
+/// describe a number far too large to hold in memory.
+///
+/// # Arguments
+/// 
+/// * `name` - A string slice holding the name of a number, with each word in
+///   lowercase and separated by whitespace. If any word is not part of the
+///   Knuth -yllion system, or the words are not in an order that `full_name`
+///   would output, this function will return an Err. If the number would have
+///   more than 2^24 digits, `ParseError::InputTooLarge` is returned instead;
+///   `parse_power_of_ten` can read the names of such large numbers.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::parse_name;
+/// let name = "twelve myllion forty two myriad sixty two hundred eight";
+/// let digits = parse_name(name).unwrap();
+/// assert_eq!("1200426208", digits.as_str());
+/// ```
+pub fn parse_name(name: &str) -> Result<String, ParseError> {
+	let words = name.split_whitespace().collect::<Vec<&str>>();
+	if words.is_empty() { return Err(ParseError::Empty); }
+	if words == ["zero"] { return Ok(String::from("0")); }
+
+	let latin = latin_values();
+	let terms = parse_terms(words.as_slice(), &latin)?;
+	digits_from_terms(terms.as_slice())
 }
 
+/// Reads a name given by `power_of_ten`, and gives back the digits of the
+/// power of ten that it describes. This is the reverse of `power_of_ten`, and
+/// can be used for names of numbers too large to write out as digits, such as
+/// those using "latin" yllions.
+///
+/// # Arguments
+/// 
+/// * `name` - A string slice holding the name of a power of ten, with each
+///   word in lowercase and separated by whitespace. If the name is not one
+///   that `power_of_ten` would output, this function will return an Err.
+/// 
+/// # Example
+/// 
+/// ```
+/// use googology::knuth_yllion::parse_power_of_ten;
+/// let power = parse_power_of_ten("one hundred myllion tryllion").unwrap();
+/// assert_eq!("42", power.as_str());
+/// ```
+pub fn parse_power_of_ten(name: &str) -> Result<String, ParseError> {
+	let words = name.split_whitespace().collect::<Vec<&str>>();
+	let latin = latin_values();
+	let (leading, rest) = match words.split_first() {
+		None => return Err(ParseError::Empty),
+		Some((&"one", rest)) => (0u32, rest),
+		Some((&"ten", rest)) => (1u32, rest),
+		Some((&word, _)) => return Err(unexpected_word(word, &latin)),
+	};
+
+	// Each word after the first adds a single bit to the power, and must
+	// be larger than the word before it.
+	let mut power = BigUint::from(leading);
+	let mut last = 0;
+	for &word in rest {
+		let n = if word == "hundred" { 1 }
+		else { parse_yllion(word, &latin)? };
+
+		if n <= last { return Err(ParseError::InvalidName); }
+		power += BigUint::one() << n;
+		last = n;
+	}
+
+	Ok(power.to_str_radix(10))
+}
+
+impl NamingSystem for Knuth {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits)
+	}
+
+	fn parse_name(&self, name: &str) -> Result<String, ParseError> {
+		parse_name(name)
+	}
+
+	fn parse_power_of_ten(&self, name: &str) -> Result<String, ParseError> {
+		parse_power_of_ten(name)
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::common::random_digits;
+	use crate::{Case, Separator};
 
 	#[test]
 	fn small_numbers() -> Result<(), ParseError> {
@@ -342,4 +1028,214 @@ mod tests {
 		);
 		Ok(())
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn parse_very_large_numbers() -> Result<(), ParseError> {
+		let myriad_myllion = parse_name("twelve myriad myllion forty two myriad")?;
+		let byllion = parse_name("one myriad byllion one myriad myllion")?;
+		assert_eq!("12000000420000", myriad_myllion.as_str());
+		assert_eq!("100000001000000000000", byllion.as_str());
+		Ok(())
+	}
+
+	#[test]
+	fn parse_invalid_names() {
+		let unknown = parse_name("one thousand");
+		let repeated = parse_name("one myriad one myriad");
+		let nothing_before = parse_name("myllion");
+		let latin_too_small = parse_name("one latinninehundredyllion");
+		assert_eq!(Err(ParseError::UnknownWord), unknown);
+		assert_eq!(Err(ParseError::InvalidName), repeated);
+		assert_eq!(Err(ParseError::InvalidName), nothing_before);
+		assert_eq!(Err(ParseError::InvalidName), latin_too_small);
+
+		// A name this short could otherwise ask for trillions of digits.
+		let huge = parse_name("one quadragintyllion");
+		assert_eq!(Err(ParseError::InputTooLarge), huge);
+	}
+
+	#[test]
+	fn parse_latin_yllion() -> Result<(), ParseError> {
+		let latin = latin_values();
+		assert_eq!(1002, parse_yllion("latintenhundredyllion", &latin)?);
+		assert_eq!(10002, parse_yllion("latinonemyriadyllion", &latin)?);
+		assert_eq!(10002, parse_yllion("latinmyriadyllion", &latin)?);
+		assert_eq!(
+			12345680,
+			parse_yllion("latintwelvehundredthirtyfourmyriadfiftysixhundredseventyeightyllion", &latin)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_round_trip() -> Result<(), ParseError> {
+		let mut seed: u64 = 42;
+		for len in (1..80).chain(250..270) {
+			let digits = random_digits(len, &mut seed);
+
+			let name = full_name(digits.as_str())?;
+			assert_eq!(digits, parse_name(name.as_str())?);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn parse_powers() -> Result<(), ParseError> {
+		let small = parse_power_of_ten("ten hundred myriad")?;
+		let latin = parse_power_of_ten("one latintenhundredyllion")?;
+		let reordered = parse_power_of_ten("one myllion myriad");
+		assert_eq!("7", small.as_str());
+		assert_eq!((BigUint::one() << 1002usize).to_str_radix(10), latin);
+		assert_eq!(Err(ParseError::InvalidName), reordered);
+		assert_eq!(Err(ParseError::UnknownWord), parse_power_of_ten("one gazillion"));
+		assert_eq!(Err(ParseError::UnknownWord), parse_power_of_ten("gazillion myllion"));
+		assert_eq!(Err(ParseError::InvalidName), parse_power_of_ten("two myllion"));
+
+		for power in 0..1100u32 {
+			let name = power_of_ten(power.to_string().as_str())?;
+			assert_eq!(power.to_string(), parse_power_of_ten(name.as_str())?);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn digits_from_iter() {
+		let mut name = String::new();
+		let too_few = write_full_name_from_iter(b"4200".iter().copied(), 5, &mut name);
+		let too_many = write_full_name_from_iter(b"4200".iter().copied(), 3, &mut name);
+		let all_zero = write_full_name_from_iter(b"0000".iter().copied(), 3, &mut name);
+		assert_eq!(Err(ParseError::LengthMismatch), too_few);
+		assert_eq!(Err(ParseError::LengthMismatch), too_many);
+		assert_eq!(Err(ParseError::LengthMismatch), all_zero);
+	}
+
+	#[test]
+	fn native_integers() -> Result<(), ParseError> {
+		assert_eq!("zero", full_name_of(0u8)?);
+		assert_eq!(full_name("18446744073709551615")?, full_name_of(u64::MAX)?);
+		assert_eq!(full_name(u128::MAX.to_string().as_str())?, full_name_of(u128::MAX)?);
+		let big = BigUint::from(10u32).pow(32);
+		assert_eq!("one tryllion", full_name_of(big)?);
+
+		for power in [0u32, 1, 2, 3, 4, 8, 16, 42, 1_000_000].iter() {
+			assert_eq!(
+				power_of_ten(power.to_string().as_str())?,
+				power_of_ten_of(&BigUint::from(*power))?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn ordinals() -> Result<(), ParseError> {
+		assert_eq!("zeroth", ordinal_name("0")?);
+		assert_eq!("third", ordinal_name("3")?);
+		assert_eq!("seventieth", ordinal_name("70")?);
+		assert_eq!("one hundredth", ordinal_name("100")?);
+		assert_eq!("one myriadth", ordinal_name("10000")?);
+		assert_eq!("one myllionth", ordinal_name("100000000")?);
+		assert_eq!("twelve myriad fifth", ordinal_name("120005")?);
+		assert_eq!("one byllionth", ordinal_name("10000000000000000")?);
+		assert_eq!(Err(ParseError::NegativeNumber), ordinal_name("-1"));
+		Ok(())
+	}
+
+	#[test]
+	fn negative_numbers() -> Result<(), ParseError> {
+		assert_eq!("minus one myllion", full_name("-100000000")?);
+		assert_eq!("negative twelve", signed_full_name("-12", "negative")?);
+		assert_eq!("twelve", full_name("+12")?);
+		assert_eq!("zero", full_name("-0")?);
+		assert_eq!(Err(ParseError::Empty), full_name("+"));
+
+		assert_eq!("one myriad", power_of_ten("+4")?);
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-8"));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-0"));
+		assert_eq!(Err(ParseError::InvalidDigit), reciprocal_power_of_ten("-8"));
+
+		let negative = Err(ParseError::NegativeNumber);
+		assert_eq!(Some(ParseError::NegativeNumber), words("-12").err());
+		assert_eq!(negative, ordinal_name("-12"));
+		assert_eq!(negative, approximate_name("-12", 2, Rounding::HalfUp, MantissaStyle::Digits));
+		assert_eq!("twelfth", ordinal_name("+12")?);
+		Ok(())
+	}
+
+	#[test]
+	fn decimals() -> Result<(), ParseError> {
+		assert_eq!("twelve point five", decimal_name("12.5", FractionStyle::Digits)?);
+		assert_eq!("twelve and five tenths", decimal_name("12.5", FractionStyle::Units)?);
+		assert_eq!("one hundredth", decimal_name("0.01", FractionStyle::Units)?);
+		assert_eq!("three myriadths", decimal_name("0.0003", FractionStyle::Units)?);
+		assert_eq!("one myllionth", decimal_name("0.00000001", FractionStyle::Units)?);
+		assert_eq!("minus one myriad point zero", decimal_name("-10000.0", FractionStyle::Digits)?);
+		assert_eq!(Err(ParseError::InvalidDigit), decimal_name("1e5", FractionStyle::Units));
+		Ok(())
+	}
+
+	#[test]
+	fn reciprocal_powers() -> Result<(), ParseError> {
+		assert_eq!("one", reciprocal_power_of_ten("0")?);
+		assert_eq!("one tenth", reciprocal_power_of_ten("1")?);
+		assert_eq!("one ten hundredth", reciprocal_power_of_ten("3")?);
+		assert_eq!("one myriadth", reciprocal_power_of_ten("4")?);
+		assert_eq!("one myllionth", reciprocal_power_of_ten("8")?);
+		assert_eq!("one ten hundred myriad myllion byllionth", reciprocal_power_of_ten("31")?);
+
+		for power in 1..40 {
+			let decimal = format!("0.{}1", "0".repeat(power - 1));
+			assert_eq!(
+				decimal_name(decimal.as_str(), FractionStyle::Units)?,
+				reciprocal_power_of_ten(power.to_string().as_str())?
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn scientific_notation() -> Result<(), ParseError> {
+		assert_eq!("forty two myllion", scientific_name("4.2e9", 20)?);
+		assert_eq!("one point two five", scientific_name("125e-2", 20)?);
+		assert_eq!("one point two times one myllion", scientific_name("1.2e8", 5)?);
+		assert_eq!("one point two times one myriadth", scientific_name("1.2e-4", 4)?);
+		assert_eq!(Err(ParseError::InvalidDigit), scientific_name("1.2e-4-", 5));
+		Ok(())
+	}
+
+	#[test]
+	fn approximate_names() -> Result<(), ParseError> {
+		let digits = |n, sig| approximate_name(n, sig, Rounding::HalfUp, MantissaStyle::Digits);
+		assert_eq!("1234", digits("1234", 4)?);
+		assert_eq!("about 1.2 myriad", digits("12345", 2)?);
+		assert_eq!("about 1200 myllion", digits("123456789012", 2)?);
+		assert_eq!("1 myriad myllion", digits("1000000000000", 2)?);
+		assert_eq!("about 1 byllion", digits("9999999999999999", 3)?);
+
+		let words = approximate_name("123456789012", 2, Rounding::HalfUp, MantissaStyle::Words)?;
+		assert_eq!("about twelve hundred myllion", words);
+		Ok(())
+	}
+
+	#[test]
+	fn styled_names() -> Result<(), ParseError> {
+		for &digits in ["0", "4200", "12000000420000", "100000000"].iter() {
+			assert_eq!(full_name(digits)?, full_name_with(digits, &Style::default())?);
+		}
+
+		let british = Style { hyphenate: true, and: true, separator: Separator::Comma, case: Case::Lower };
+		assert_eq!("forty-two hundred and six", full_name_with("4206", &british)?);
+		assert_eq!("one myriad and six", full_name_with("10006", &british)?);
+		assert_eq!("one myriad myllion, one hundred myriad", full_name_with("1000001000000", &british)?);
+		Ok(())
+	}
+
+	#[test]
+	fn cased_names() -> Result<(), ParseError> {
+		let title = Style { hyphenate: true, case: Case::Title, ..Style::default() };
+		assert_eq!("Forty-Two Hundred Myllion", full_name_with("420000000000", &title)?);
+		let latin = format!("1{}", "0".repeat(1 << 12));
+		let upper = Style { case: Case::Upper, ..Style::default() };
+		assert_eq!("ONE DECYLLION", full_name_with(latin.as_str(), &upper)?);
+		Ok(())
+	}
+}
@@ -15,16 +15,16 @@
 //! 
 //! This system supports three different "scale" parameters:
 //! * `Scale::Short` uses a modern English naming convention where each new "illion"
-//! is scaled by powers of 1,000. The value of 10^9 is called `"one billion"`.
+//!   is scaled by powers of 1,000. The value of 10^9 is called `"one billion"`.
 //! * `Scale::LongBritish` uses an older convention used in the UK prior to 1974.
-//! Each new "illion" is scaled by powers of 1,000,000, and powers of 1,000 that
-//! lie in between "illions" are prefixed with "thousand". Thus, the value of 10^9
-//! is called `"one thousand million"`.
+//!   Each new "illion" is scaled by powers of 1,000,000, and powers of 1,000 that
+//!   lie in between "illions" are prefixed with "thousand". Thus, the value of 10^9
+//!   is called `"one thousand million"`.
 //! * `Scale::LongPeletier` uses a naming convention still in use in many European
-//! languages. Similar to `Scale::LongBritish`, "illions" are scaled by powers of
-//! 1,000,000. However, instead of prefixing the in betweens with "thousand", they
-//! are instead suffixed with "ard" instead of "on". Thus, the value of 10^9 is
-//! called `"one milliard"`. 
+//!   languages. Similar to `Scale::LongBritish`, "illions" are scaled by powers of
+//!   1,000,000. However, instead of prefixing the in betweens with "thousand", they
+//!   are instead suffixed with "ard" instead of "on". Thus, the value of 10^9 is
+//!   called `"one milliard"`. 
 //! 
 //! An alternative system called the Knuth-Yllion system is also provided. Here,
 //! rather than scaling by powers of 1,000 or powers of 1,000,000, the scaling is
@@ -33,27 +33,286 @@
 //! in between, we describe an "yllion" number with those of lesser magnitude. For
 //! example, 10^14 would be called "one hundred myriad myllion".
 //! 
-//! Two functions are provided in each module:
+//! Three functions are provided in each module:
 //! * `full_name` gives a name to any arbitrary number, given a base-10 string
-//! representation of its digits.
+//!   representation of its digits.
 //! * `power_of_ten` gives a name to a power of ten. This can be useful for numbers
-//! that may be so large that storing them in memory would be impossible or
-//! otherwise impractical.
+//!   that may be so large that storing them in memory would be impossible or
+//!   otherwise impractical.
+//! * `parse_name` reads a name given by `full_name`, and gives back the base-10
+//!   string representation of its digits.
+//! 
+//! For numbers that are too large for their names to fit on screen, the
+//! `notation` module writes them in compact notations instead, such as the
+//! letter notation used by many incremental games.
+//!
+//! The `cheque` module writes currency amounts in words, as on a cheque, such as
+//! "One million two hundred thirty-four thousand five hundred sixty-seven and
+//! 89/100 dollars".
+//!
+//! The `indian` module names numbers in the Indian numbering system, such as
+//! "three crore forty lakh", with extensions for numbers beyond kharab.
+//!
+//! The `east_asian` module names numbers with the myriad units used in Chinese,
+//! Japanese and Korean, such as "一億二千万" or "ichi oku nisen man".
+//!
+//! The `bowers` module follows Jonathan Bowers' extension of the -illion system,
+//! which names 10^3000003 "one micrillion" rather than "one millinillinillion".
+//!
+//! The `rowlett` module uses Russ Rowlett's Greek-based names, such as "gillion"
+//! for 10^9, which mean the same thing in every country.
+//!
+//! The `historical` module writes numbers as they appeared in earlier centuries,
+//! such as in Chuquet's 1484 scheme, where 10^12 is "one byllion".
+
 
+use std::fmt;
 
 mod common;
+pub mod bowers;
+pub mod cheque;
 pub mod conway_wechsler;
+pub mod east_asian;
+pub mod historical;
+pub mod indian;
 pub mod knuth_yllion;
+pub mod notation;
+pub mod rowlett;
+
+/// Re-exported so that callers of `full_name_of` and `power_of_ten_of` need
+/// not depend on num-bigint themselves.
+pub use num_bigint::BigUint;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseError {
 	/// Input was the empty string.
 	Empty,
-	/// Input is too large to be given a name by knuth_yllion.
+	/// Input is too large to be given a name by the chosen naming system.
 	InputTooLarge,
 	/// The parser entered some sort of invalid state.
 	/// If this error is returned, there is a bug in the googology crate.
 	InternalError,
 	/// Input contains some digits other than 0-9.
 	InvalidDigit,
+	/// Input is made of known words, but not in an order that the naming
+	/// system would give.
+	InvalidName,
+	/// A source of digits held a different number of digits than expected.
+	LengthMismatch,
+	/// A power of ten was given a negative exponent.
+	NegativeExponent,
+	/// A negative number was given to a function which only names numbers
+	/// that are not negative.
+	NegativeNumber,
+	/// Input contains a word that is not part of the naming system.
+	UnknownWord,
+	/// The naming system does not support the requested operation.
+	Unsupported,
+	/// The writer given to one of the write functions returned an error.
+	WriteFailed,
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let description = match self {
+			ParseError::Empty => "input was empty",
+			ParseError::InputTooLarge => "input is too large to be given a name",
+			ParseError::InternalError => "internal error in the googology crate",
+			ParseError::InvalidDigit => "input contains a character other than 0-9",
+			ParseError::InvalidName => "words are not in an order the naming system would give",
+			ParseError::LengthMismatch => "input has a different number of digits than expected",
+			ParseError::NegativeExponent => "power of ten has a negative exponent",
+			ParseError::NegativeNumber => "input is negative, which cannot be named here",
+			ParseError::UnknownWord => "input contains a word not in the naming system",
+			ParseError::Unsupported => "operation is not supported by the naming system",
+			ParseError::WriteFailed => "failed to write to the output",
+		};
+		f.write_str(description)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<fmt::Error> for ParseError {
+	fn from(_: fmt::Error) -> Self {
+		ParseError::WriteFailed
+	}
+}
+
+/// A single word from the name of a number, as given by the `words` function
+/// of each naming system. The `Display` implementation for each word gives the
+/// same text that is used for it by `full_name`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Word {
+	/// A number named with a single word, such as "zero", "twelve" or "forty".
+	Small(&'static str),
+	/// The word "hundred".
+	Hundred,
+	/// The word "thousand".
+	Thousand,
+	/// The word "myriad", which the Knuth -yllion system uses for 10^4.
+	Myriad,
+	/// A Conway-Wechsler name ending in "illion", given by the value of its
+	/// latin prefixes. For example, 1 is "million" and 1000 is "millinillion".
+	Zillion(usize),
+	/// A long scale Conway-Wechsler name ending in "illiard", given by the
+	/// value of its latin prefixes. For example, 1 is "milliard".
+	Zilliard(usize),
+	/// A Knuth -yllion name, given by the value of its latin prefix.
+	/// For example, 1 is "myllion" and 2 is "byllion".
+	Yllion(usize),
+}
+
+/// A parameter for the `decimal_name` function of each naming system, which
+/// indicates how the digits after the decimal point should be named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FractionStyle {
+	/// Each digit is named in turn, so 3.14 will be called
+	/// "three point one four".
+	Digits,
+	/// The digits are named as a count of some power of ten, so 3.14 will be
+	/// called "three and fourteen hundredths".
+	Units,
+}
+
+/// A parameter for the `approximate_name` function of each naming system,
+/// which indicates how a number should be rounded to its significant figures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+	/// Digits past the significant figures are dropped.
+	Down,
+	/// The last significant figure is increased whenever any nonzero digit
+	/// is dropped.
+	Up,
+	/// Rounds to the nearest value, with halves rounded up.
+	HalfUp,
+	/// Rounds to the nearest value, with halves rounded so that the last
+	/// significant figure is even.
+	HalfEven,
+}
+
+/// A parameter for the `approximate_name` function of each naming system,
+/// which indicates how the number in front of the unit should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MantissaStyle {
+	/// The mantissa is written with digits, as in "1.2 million".
+	Digits,
+	/// The mantissa is written with words, as in "one point two million".
+	Words,
+}
+
+/// A parameter for `Style` which indicates what is placed between each group
+/// of a full name, such as between "one million" and "two hundred".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+	/// Groups are separated by a space, as in "one million two hundred".
+	Space,
+	/// Groups are separated by a comma, as in "one million, two hundred".
+	Comma,
+}
+
+/// A parameter for `Style` which indicates which letters of a name should be
+/// capitals. Chained zillions such as "millinillion" are treated as a single
+/// word, so they are given only one capital.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+	/// Every letter is lowercase, as in "one hundred twenty three million".
+	Lower,
+	/// Only the first letter is a capital, as in "One hundred twenty three
+	/// million".
+	Sentence,
+	/// Every word begins with a capital, as in "One Hundred Twenty Three
+	/// Million". The word "and" is left in lowercase.
+	Title,
+	/// Every letter is a capital, as in "ONE HUNDRED TWENTY THREE MILLION".
+	Upper,
+}
+
+/// Options for how the words of a full name are written, as used by
+/// the `full_name_with` function of each naming system. The default style
+/// gives the same names as `full_name`.
+///
+/// # Example
+///
+/// ```
+/// use googology::{Case, Separator, Style};
+/// use googology::conway_wechsler::{Scale, full_name_with};
+/// let british = Style {
+///     hyphenate: true,
+///     and: true,
+///     separator: Separator::Comma,
+///     case: Case::Sentence,
+/// };
+/// let name = full_name_with("1042142", Scale::Short, &british).unwrap();
+/// assert_eq!("One million, forty-two thousand, one hundred and forty-two", name.as_str());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+	/// Whether compound tens are joined with a hyphen, as in "forty-two".
+	pub hyphenate: bool,
+	/// Whether "and" is placed after "hundred", as in "one hundred and one",
+	/// and before a final group with no hundreds, as in "one million and six".
+	pub and: bool,
+	/// What is placed between each group of the name.
+	pub separator: Separator,
+	/// Which letters of the name are capitals.
+	pub case: Case,
+}
+
+impl Default for Style {
+	fn default() -> Self {
+		Style {
+			hyphenate: false,
+			and: false,
+			separator: Separator::Space,
+			case: Case::Lower,
+		}
+	}
+}
+
+/// A common interface to each of the naming systems in this crate, so that a
+/// system may be chosen at runtime and stored as a `Box<dyn NamingSystem>`.
+/// Each method behaves the same as the function of the same name in the
+/// module for that system.
+///
+/// # Example
+///
+/// ```
+/// use googology::NamingSystem;
+/// use googology::conway_wechsler::{ConwayWechsler, Scale};
+/// use googology::knuth_yllion::Knuth;
+///
+/// let systems: Vec<Box<dyn NamingSystem>> = vec![
+///     Box::new(ConwayWechsler { scale: Scale::Short }),
+///     Box::new(Knuth),
+/// ];
+///
+/// let names = systems
+///     .iter()
+///     .map(|s| s.full_name("100000000").unwrap())
+///     .collect::<Vec<String>>();
+///
+/// assert_eq!(vec!["one hundred million", "one myllion"], names);
+/// ```
+pub trait NamingSystem {
+	/// Gives a full length name for a number represented by an arbitrary
+	/// sequence of digits.
+	fn full_name(&self, digits: &str) -> Result<String, ParseError>;
+
+	/// Gives a name for a number representing a power of ten.
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError>;
+
+	/// Reads a name given by `full_name`, and gives back the digits of the
+	/// number that it describes. Systems which cannot read names will
+	/// return `ParseError::Unsupported`.
+	fn parse_name(&self, _name: &str) -> Result<String, ParseError> {
+		Err(ParseError::Unsupported)
+	}
+
+	/// Reads a name given by `power_of_ten`, and gives back the digits of the
+	/// power of ten that it describes. Systems which cannot read names will
+	/// return `ParseError::Unsupported`.
+	fn parse_power_of_ten(&self, _name: &str) -> Result<String, ParseError> {
+		Err(ParseError::Unsupported)
+	}
 }
@@ -0,0 +1,491 @@
+//! Methods for writing large numbers in compact notations, rather than words.
+//! These are common in incremental games, where numbers quickly grow too
+//! large for their full names to fit on screen.
+//!
+//! Letter notation names each power of one thousand with a letter. The value
+//! 10^3 is "K", after which 10^6 is "a", 10^9 is "b", and so on up to "z". The
+//! letters then continue with "aa", "ab", and so on, without any upper limit.
+//!
+//! Exponent notations instead write the power of ten itself. Scientific
+//! notation gives "1.2345e26", engineering notation keeps the exponent to a
+//! multiple of three as in "123.45e24", and E-notation writes it in the manner
+//! of a spreadsheet, as in "1.2345E+26".
+//!
+//! Every function here reads the same digit strings as the `full_name` function
+//! of each naming system, and fails in the same ways. Each notation is also
+//! available as a `NamingSystem`, so that words and notations may be swapped
+//! for one another at runtime.
+
+extern crate num_traits;
+extern crate num_bigint;
+
+use num_traits::cast::ToPrimitive;
+use num_traits::identities::Zero;
+use num_bigint::BigUint;
+
+use crate::common::{
+	is_all_digits,
+	power_from_digits,
+	round_digits,
+	split_decimal,
+	split_sign
+};
+
+use crate::{NamingSystem, ParseError, Rounding};
+
+/// A parameter for `exponent_notation` which indicates how the power of ten
+/// should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentStyle {
+	/// One digit before the decimal point, as in "1.2345e26".
+	Scientific,
+	/// One to three digits before the decimal point, so that the exponent is
+	/// a multiple of three, as in "123.45e24".
+	Engineering,
+	/// One digit before the decimal point, with the exponent written with a
+	/// sign and at least two digits, as in "1.2345E+26".
+	ENotation,
+}
+
+/// Letter notation as a `NamingSystem`, using the given precision and rounding
+/// for every number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterNotation {
+	pub precision: usize,
+	pub rounding: Rounding,
+}
+
+/// Exponent notation as a `NamingSystem`, using the given style, precision
+/// and rounding for every number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentNotation {
+	pub style: ExponentStyle,
+	pub precision: usize,
+	pub rounding: Rounding,
+}
+
+// Runs a notation function on a string of digits with an optional sign, which
+// is checked in the same way as full_name. A minus sign is kept in front of
+// the result unless the number is zero.
+fn with_sign<F>(digits: &str, notation: F) -> Result<String, ParseError>
+where
+	F: FnOnce(&str) -> Result<String, ParseError>
+{
+	let (negative, digits) = split_sign(digits);
+	if !is_all_digits(digits) { return Err(ParseError::InvalidDigit); }
+	if digits.is_empty() { return Err(ParseError::Empty); }
+
+	let output = notation(digits)?;
+	if negative && digits.bytes().any(|b| b != b'0') {
+		return Ok(format!("-{}", output));
+	}
+	Ok(output)
+}
+
+// Reads a decimal mantissa along with a power of ten to multiply it by, and
+// gives back the significant digits of their product (with no leading or
+// trailing zeroes), along with the power of ten of the first of those digits.
+// Zero gives back None, as it has no significant digits.
+fn significand(mantissa: &str, exponent: &BigUint) -> Result<Option<(String, BigUint)>, ParseError> {
+	let (negative, whole, fraction) = split_decimal(mantissa)?;
+	if negative { return Err(ParseError::InvalidDigit); }
+
+	let whole = whole.trim_start_matches('0');
+	let digits = [whole, fraction].concat();
+	let significant = digits.trim_start_matches('0');
+	if significant.is_empty() { return Ok(None); }
+
+	let power = if !whole.is_empty() {
+		exponent + (whole.len() - 1)
+	} else {
+		// A mantissa such as 0.05 needs the exponent to make up for the
+		// zeroes after its decimal point.
+		let shift = digits.len() - significant.len() + 1;
+		if *exponent < BigUint::from(shift) { return Err(ParseError::NegativeExponent); }
+		exponent - shift
+	};
+
+	Ok(Some((String::from(significant.trim_end_matches('0')), power)))
+}
+
+// Rounds significant digits so that there are precision digits after the
+// decimal point once the first lead digits are placed before it, where lead
+// is given by the power of ten of the first digit. If rounding up adds a new
+// digit, the power of ten is increased to match. Gives back the digits before
+// and after the decimal point, along with the (possibly new) power of ten.
+fn round_to_group(
+	digits: &str,
+	power: BigUint,
+	group: u32,
+	precision: usize,
+	rounding: Rounding
+) -> (String, String, BigUint) {
+	let lead = |p: &BigUint| (p % group).to_usize().unwrap_or(0) + 1;
+
+	let (mut kept, carried, _) = round_digits(digits, lead(&power) + precision, rounding);
+	let power = if carried { power + 1u32 } else { power };
+	kept.resize(lead(&power) + precision, b'0');
+
+	let fraction = kept.split_off(lead(&power));
+	let to_string = |v: Vec<u8>| v.into_iter().map(char::from).collect::<String>();
+	(to_string(kept), to_string(fraction), power)
+}
+
+// Gives the letters for a power of one thousand. One is "K", two is "a",
+// and each value after "z" adds another letter, as in "aa", "ab", and so on.
+fn letters(unit: BigUint) -> String {
+	if unit.is_zero() { return String::new(); }
+	if unit == BigUint::from(1u32) { return String::from("K"); }
+
+	// The letters count up from "a" much like digits, except that there is
+	// no letter for zero. So "z" is followed by "aa" rather than "ba".
+	let mut n = unit - 1u32;
+	let mut letters = Vec::new();
+	while !n.is_zero() {
+		n -= 1u32;
+		let letter = (&n % 26u32).to_u8().unwrap_or(0);
+		letters.push(char::from(b'a' + letter));
+		n /= 26u32;
+	}
+
+	letters.into_iter().rev().collect()
+}
+
+/// Writes a number in letter notation, such as "1.23a" for 1,230,000. The
+/// number is given with exactly `precision` digits after the decimal point.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `precision` - How many digits to write after the decimal point.
+/// * `rounding` - How any further digits are rounded.
+///
+/// # Example
+///
+/// ```
+/// use googology::Rounding;
+/// use googology::notation::letter_notation;
+/// let thousands = letter_notation("12345", 1, Rounding::HalfUp).unwrap();
+/// let millions = letter_notation("1234567", 2, Rounding::HalfUp).unwrap();
+/// assert_eq!("12.3K", thousands.as_str());
+/// assert_eq!("1.23a", millions.as_str());
+/// ```
+pub fn letter_notation(
+	digits: &str,
+	precision: usize,
+	rounding: Rounding
+) -> Result<String, ParseError> {
+	with_sign(digits, |d| letter_notation_of(d, &BigUint::zero(), precision, rounding))
+}
+
+/// Writes a power of ten in letter notation, in the same manner as
+/// `letter_notation`. This takes the same input as the `power_of_ten` function
+/// of each naming system, so that players may switch between the two.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err.
+/// * `precision` - How many digits to write after the decimal point.
+///
+/// # Example
+///
+/// ```
+/// use googology::notation::letter_power_of_ten;
+/// let name = letter_power_of_ten("100", 0).unwrap();
+/// assert_eq!("10af", name.as_str());
+/// ```
+pub fn letter_power_of_ten(digits: &str, precision: usize) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?;
+	letter_notation_of("1", &power, precision, Rounding::Down)
+}
+
+/// Writes a number given as a mantissa and a power of ten in letter notation,
+/// in the same manner as `letter_notation`. As the exponent is a `BigUint`,
+/// there is no limit on how large the number may be.
+///
+/// # Arguments
+///
+/// * `mantissa` - A string slice that holds a representation of the mantissa
+///   using only the digits 0-9 and an optional decimal point. If any other
+///   character is present, this function will return an Err. If the whole
+///   number would be less than one, `ParseError::NegativeExponent` is returned.
+/// * `exponent` - The power of ten to multiply the mantissa by.
+/// * `precision` - How many digits to write after the decimal point.
+/// * `rounding` - How any further digits are rounded.
+///
+/// # Example
+///
+/// ```
+/// use googology::{BigUint, Rounding};
+/// use googology::notation::letter_notation_of;
+/// let name = letter_notation_of("4.5678", &BigUint::from(9u32), 2, Rounding::Down);
+/// assert_eq!("4.56b", name.unwrap().as_str());
+/// ```
+pub fn letter_notation_of(
+	mantissa: &str,
+	exponent: &BigUint,
+	precision: usize,
+	rounding: Rounding
+) -> Result<String, ParseError> {
+	let (digits, power) = match significand(mantissa, exponent)? {
+		Some(s) => s,
+		None => (String::from("0"), BigUint::zero()),
+	};
+
+	let (whole, fraction, power) = round_to_group(digits.as_str(), power, 3, precision, rounding);
+	let mut output = whole;
+	if !fraction.is_empty() {
+		output.push('.');
+		output.push_str(fraction.as_str());
+	}
+	output.push_str(letters(power / 3u32).as_str());
+	Ok(output)
+}
+
+/// Writes a number in an exponent notation, such as "1.2345e26". The mantissa
+/// is given with exactly `precision` digits after the decimal point.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err.
+/// * `style` - Which notation the power of ten is written in.
+/// * `precision` - How many digits to write after the decimal point.
+/// * `rounding` - How any further digits are rounded.
+///
+/// # Example
+///
+/// ```
+/// use googology::Rounding;
+/// use googology::notation::{ExponentStyle, exponent_notation};
+/// let digits = format!("123456{}", "0".repeat(21));
+/// let sci = exponent_notation(&digits, ExponentStyle::Scientific, 4, Rounding::HalfUp);
+/// let eng = exponent_notation(&digits, ExponentStyle::Engineering, 2, Rounding::HalfUp);
+/// let e = exponent_notation(&digits, ExponentStyle::ENotation, 4, Rounding::HalfUp);
+/// assert_eq!("1.2346e26", sci.unwrap().as_str());
+/// assert_eq!("123.46e24", eng.unwrap().as_str());
+/// assert_eq!("1.2346E+26", e.unwrap().as_str());
+/// ```
+pub fn exponent_notation(
+	digits: &str,
+	style: ExponentStyle,
+	precision: usize,
+	rounding: Rounding
+) -> Result<String, ParseError> {
+	with_sign(digits, |d| exponent_notation_of(d, &BigUint::zero(), style, precision, rounding))
+}
+
+/// Writes a power of ten in an exponent notation, in the same manner as
+/// `exponent_notation`. This takes the same input as the `power_of_ten`
+/// function of each naming system.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err.
+/// * `style` - Which notation the power of ten is written in.
+/// * `precision` - How many digits to write after the decimal point.
+///
+/// # Example
+///
+/// ```
+/// use googology::notation::{ExponentStyle, exponent_power_of_ten};
+/// let name = exponent_power_of_ten("100", ExponentStyle::Engineering, 1).unwrap();
+/// assert_eq!("10.0e99", name.as_str());
+/// ```
+pub fn exponent_power_of_ten(
+	digits: &str,
+	style: ExponentStyle,
+	precision: usize
+) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?;
+	exponent_notation_of("1", &power, style, precision, Rounding::Down)
+}
+
+/// Writes a number given as a mantissa and a power of ten in an exponent
+/// notation, in the same manner as `exponent_notation`. The mantissa need not
+/// be normalised, so "123.4" and 5 gives "1.234e7" in scientific notation.
+///
+/// # Arguments
+///
+/// * `mantissa` - A string slice that holds a representation of the mantissa
+///   using only the digits 0-9 and an optional decimal point. If any other
+///   character is present, this function will return an Err. If the whole
+///   number would be less than one, `ParseError::NegativeExponent` is returned.
+/// * `exponent` - The power of ten to multiply the mantissa by.
+/// * `style` - Which notation the power of ten is written in.
+/// * `precision` - How many digits to write after the decimal point.
+/// * `rounding` - How any further digits are rounded.
+///
+/// # Example
+///
+/// ```
+/// use googology::{BigUint, Rounding};
+/// use googology::notation::{ExponentStyle, exponent_notation_of};
+/// let style = ExponentStyle::Scientific;
+/// let name = exponent_notation_of("123.4", &BigUint::from(5u32), style, 3, Rounding::Down);
+/// assert_eq!("1.234e7", name.unwrap().as_str());
+/// ```
+pub fn exponent_notation_of(
+	mantissa: &str,
+	exponent: &BigUint,
+	style: ExponentStyle,
+	precision: usize,
+	rounding: Rounding
+) -> Result<String, ParseError> {
+	let (digits, power) = match significand(mantissa, exponent)? {
+		Some(s) => s,
+		None => (String::from("0"), BigUint::zero()),
+	};
+
+	let group = match style { ExponentStyle::Engineering => 3, _ => 1 };
+	let (whole, fraction, power) = round_to_group(digits.as_str(), power, group, precision, rounding);
+	let power = &power - &power % group;
+
+	let mut output = whole;
+	if !fraction.is_empty() {
+		output.push('.');
+		output.push_str(fraction.as_str());
+	}
+	match style {
+		ExponentStyle::ENotation => output.push_str(format!("E+{:0>2}", power).as_str()),
+		_ => output.push_str(format!("e{}", power).as_str()),
+	}
+	Ok(output)
+}
+
+impl NamingSystem for LetterNotation {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		letter_notation(digits, self.precision, self.rounding)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		letter_power_of_ten(digits, self.precision)
+	}
+}
+
+impl NamingSystem for ExponentNotation {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		exponent_notation(digits, self.style, self.precision, self.rounding)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		exponent_power_of_ten(digits, self.style, self.precision)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn letters_count_up() {
+		let expected = [
+			(0u32, ""), (1, "K"), (2, "a"), (3, "b"), (27, "z"), (28, "aa"),
+			(29, "ab"), (53, "az"), (54, "ba"), (703, "zz"), (704, "aaa"),
+		];
+		for &(unit, name) in expected.iter() {
+			assert_eq!(name, letters(BigUint::from(unit)));
+		}
+	}
+
+	#[test]
+	fn letter_numbers() -> Result<(), ParseError> {
+		assert_eq!("0.00", letter_notation("000", 2, Rounding::HalfUp)?);
+		assert_eq!("999", letter_notation("999", 0, Rounding::HalfUp)?);
+		assert_eq!("999.0", letter_notation("999", 1, Rounding::HalfUp)?);
+		assert_eq!("1.00a", letter_notation("999999", 2, Rounding::HalfUp)?);
+		assert_eq!("999.99K", letter_notation("999999", 2, Rounding::Down)?);
+		assert_eq!("12.3K", letter_notation("0012345", 1, Rounding::Down)?);
+		assert_eq!("1.5z", letter_notation(&format!("15{}", "0".repeat(80)), 1, Rounding::HalfUp)?);
+		assert_eq!("1aa", letter_notation(&format!("1{}", "0".repeat(84)), 0, Rounding::HalfUp)?);
+
+		assert_eq!(Err(ParseError::Empty), letter_notation("", 2, Rounding::HalfUp));
+		assert_eq!(Err(ParseError::InvalidDigit), letter_notation("1.5", 2, Rounding::HalfUp));
+		Ok(())
+	}
+
+	#[test]
+	fn letter_powers() -> Result<(), ParseError> {
+		assert_eq!("1", letter_power_of_ten("0", 0)?);
+		assert_eq!("100.0K", letter_power_of_ten("5", 1)?);
+		assert_eq!("1a", letter_power_of_ten("6", 0)?);
+		assert_eq!(Err(ParseError::NegativeExponent), letter_power_of_ten("-6", 0));
+
+		let huge = BigUint::from(10u32).pow(30) * 3u32;
+		let name = letter_notation_of("2.5", &huge, 1, Rounding::HalfUp)?;
+		assert!(name.starts_with("2.5"));
+		assert!(name.bytes().skip(3).all(|b| b.is_ascii_lowercase()));
+
+		assert_eq!("50.0K", letter_notation_of("0.05", &BigUint::from(6u32), 1, Rounding::HalfUp)?);
+		assert_eq!(Err(ParseError::NegativeExponent), letter_notation_of("0.05", &BigUint::from(1u32), 1, Rounding::HalfUp));
+		assert_eq!("0", letter_notation_of("0.0", &BigUint::from(1u32), 0, Rounding::HalfUp)?);
+		Ok(())
+	}
+
+	#[test]
+	fn exponent_numbers() -> Result<(), ParseError> {
+		let sci = |d, p| exponent_notation(d, ExponentStyle::Scientific, p, Rounding::HalfUp);
+		let eng = |d, p| exponent_notation(d, ExponentStyle::Engineering, p, Rounding::HalfUp);
+		let e = |d, p| exponent_notation(d, ExponentStyle::ENotation, p, Rounding::HalfUp);
+
+		assert_eq!("0.00e0", sci("0", 2)?);
+		assert_eq!("4.2e1", sci("42", 1)?);
+		assert_eq!("4e1", sci("42", 0)?);
+		assert_eq!("1e2", sci("99", 0)?);
+		assert_eq!("1.2346e27", sci("1234567000000000000000000000", 4)?);
+		assert_eq!("-1.5e3", sci("-1500", 1)?);
+		assert_eq!("1.5e3", sci("0001500", 1)?);
+
+		assert_eq!("42.0e0", eng("42", 1)?);
+		assert_eq!("1.50e3", eng("1500", 2)?);
+		assert_eq!("150e3", eng("150000", 0)?);
+		assert_eq!("10.0e3", eng("9999", 1)?);
+		assert_eq!("123.45e24", eng("123450000000000000000000000", 2)?);
+
+		assert_eq!("4.20E+01", e("42", 2)?);
+		assert_eq!("1.2E+100", e(format!("12{}", "0".repeat(99)).as_str(), 1)?);
+
+		let down = exponent_notation("1999", ExponentStyle::Scientific, 2, Rounding::Down)?;
+		assert_eq!("1.99e3", down);
+		Ok(())
+	}
+
+	#[test]
+	fn same_errors_as_full_name() {
+		use crate::conway_wechsler::{Scale, full_name};
+		for &digits in ["", "-", "+", "12a", "1.5", "--1", "1-"].iter() {
+			let notation = exponent_notation(digits, ExponentStyle::Scientific, 2, Rounding::HalfUp);
+			let letters = letter_notation(digits, 2, Rounding::HalfUp);
+			let name = full_name(digits, Scale::Short);
+			assert_eq!(name.clone().err(), notation.err());
+			assert_eq!(name.err(), letters.err());
+		}
+	}
+
+	#[test]
+	fn notations_as_naming_systems() -> Result<(), ParseError> {
+		let systems: Vec<Box<dyn NamingSystem>> = vec![
+			Box::new(LetterNotation { precision: 1, rounding: Rounding::HalfUp }),
+			Box::new(ExponentNotation {
+				style: ExponentStyle::Scientific,
+				precision: 1,
+				rounding: Rounding::HalfUp
+			}),
+		];
+
+		let names = systems
+			.iter()
+			.map(|s| s.full_name("1234567"))
+			.collect::<Result<Vec<String>, ParseError>>()?;
+		assert_eq!(vec!["1.2a", "1.2e6"], names);
+		assert_eq!(Err(ParseError::Unsupported), systems[0].parse_name("1.2a"));
+		Ok(())
+	}
+}
@@ -0,0 +1,215 @@
+//! Methods for the Greek-based -illion system proposed by Russ Rowlett. As the
+//! short and long scales give different values to names such as "billion",
+//! Rowlett suggested new names built from the Greek numbers, as in the SI
+//! prefixes giga, tera and peta. Each new name is 1000 times the last, and is
+//! given by the Greek number for its power of 1000:
+//! * 10^6 is still one million, as both scales agree on this name.
+//! * 10^9 is one gillion, from giga.
+//! * 10^12 is one tetrillion, 10^15 is one pentillion, and so on.
+//! * 10^30 is one dekillion, 10^60 is one icosillion and 10^63 is one
+//!   icosikaihenillion.
+//!
+//! Rowlett only gives names up to the hectillion (10^300). The same Greek
+//! numbers are used here to extend this up to the enneacosienneacontakaiennillion
+//! (10^2997), so that numbers below 10^3000 can be named.
+
+use std::fmt;
+use num_traits::cast::ToPrimitive;
+
+use crate::common::{
+	greek_prefix,
+	name_string,
+	num_from_slice,
+	power_from_digits,
+	split_number,
+	write_myriad_words,
+	write_word
+};
+
+use crate::{NamingSystem, ParseError};
+
+// How many groups of three digits may be named, one for each power of 1000
+// that greek_prefix can give a name to.
+const MAX_GROUPS: usize = 1000;
+
+/// The Rowlett -illion system as a `NamingSystem`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rowlett;
+
+// Writes the word for the given power of 1000, which must be at least one.
+fn write_illion<W: fmt::Write>(
+	power: usize,
+	first: &mut bool,
+	out: &mut W
+) -> Result<(), ParseError> {
+	if power == 1 {
+		write_word("thousand", first, out)?;
+	} else {
+		let prefix = greek_prefix(power)?;
+		write_word(prefix.as_str(), first, out)?;
+		out.write_str("illion")?;
+	}
+	Ok(())
+}
+
+/// Gives the full length name of a number in the Rowlett system.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err. Numbers of
+///   10^3000 or more give `ParseError::InputTooLarge`.
+///
+/// # Example
+///
+/// ```
+/// use googology::rowlett::full_name;
+/// let name = full_name("12000000345000").unwrap();
+/// assert_eq!("twelve tetrillion three hundred forty five thousand", name.as_str());
+/// ```
+pub fn full_name(digits: &str) -> Result<String, ParseError> {
+	name_string(|out| write_full_name(digits, out))
+}
+
+/// Writes the full length name of a number in the same manner as `full_name`.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the number
+///   using only the digits 0-9, optionally preceded by a sign. If any other
+///   character is present, this function will return an Err without writing
+///   anything.
+/// * `out` - The writer to send the name to. Any error from it is given back
+///   as `ParseError::WriteFailed`.
+///
+/// # Example
+///
+/// ```
+/// use googology::rowlett::write_full_name;
+/// let mut name = String::new();
+/// write_full_name("-3000000000", &mut name).unwrap();
+/// assert_eq!("minus three gillion", name.as_str());
+/// ```
+pub fn write_full_name<W: fmt::Write>(digits: &str, out: &mut W) -> Result<(), ParseError> {
+	let (negative, digits) = split_number(digits)?;
+	if digits.is_empty() {
+		out.write_str("zero")?;
+		return Ok(());
+	}
+
+	let groups = digits.len().div_ceil(3);
+	if groups > MAX_GROUPS { return Err(ParseError::InputTooLarge); }
+
+	let mut first = true;
+	if negative { write_word("minus", &mut first, out)?; }
+
+	let mut start = 0;
+	for group in (0..groups).rev() {
+		let end = digits.len() - 3*group;
+		let num = num_from_slice(digits, start, end - start);
+		start = end;
+
+		if num == 0 { continue; }
+		write_myriad_words(num, &mut first, out)?;
+		if group > 0 { write_illion(group, &mut first, out)?; }
+	}
+
+	Ok(())
+}
+
+/// Gives a name for a number representing a power of ten, such as "one
+/// gillion" for 10^9.
+///
+/// # Arguments
+///
+/// * `digits` - A string slice that holds a representation of the exponent
+///   using only the digits 0-9. If any other character is present, this function
+///   will return an Err. A negative exponent gives `ParseError::NegativeExponent`,
+///   and an exponent of 3000 or more gives `ParseError::InputTooLarge`.
+///
+/// # Example
+///
+/// ```
+/// use googology::rowlett::power_of_ten;
+/// let icosillion = power_of_ten("60").unwrap();
+/// let hectillion = power_of_ten("301").unwrap();
+/// assert_eq!("one icosillion", icosillion.as_str());
+/// assert_eq!("ten hectillion", hectillion.as_str());
+/// ```
+pub fn power_of_ten(digits: &str) -> Result<String, ParseError> {
+	let power = power_from_digits(digits)?
+		.to_usize()
+		.filter(|&p| p < 3*MAX_GROUPS)
+		.ok_or(ParseError::InputTooLarge)?;
+
+	let mut output = String::new();
+	let mut first = true;
+	write_word(["one", "ten", "one hundred"][power % 3], &mut first, &mut output)?;
+	if power >= 3 { write_illion(power / 3, &mut first, &mut output)?; }
+	Ok(output)
+}
+
+impl NamingSystem for Rowlett {
+	fn full_name(&self, digits: &str) -> Result<String, ParseError> {
+		full_name(digits)
+	}
+
+	fn power_of_ten(&self, digits: &str) -> Result<String, ParseError> {
+		power_of_ten(digits)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rowlett_names() -> Result<(), ParseError> {
+		assert_eq!("zero", full_name("-000")?);
+		assert_eq!("forty two", full_name("42")?);
+		assert_eq!("one thousand one", full_name("1001")?);
+		assert_eq!("one million", full_name("1000000")?);
+		assert_eq!(
+			"one gillion two hundred thirty four million five hundred sixty seven \
+			 thousand eight hundred ninety",
+			full_name("1234567890")?
+		);
+		assert_eq!("minus seven pentillion one", full_name("-7000000000000001")?);
+
+		let largest = "9".repeat(3000);
+		assert!(full_name(largest.as_str())?.starts_with("nine hundred ninety nine enneacosienneacontakaiennillion"));
+		let too_large = format!("1{}", "0".repeat(3000));
+		assert_eq!(Err(ParseError::InputTooLarge), full_name(too_large.as_str()));
+		assert_eq!(Err(ParseError::InvalidDigit), full_name("1e9"));
+		assert_eq!(Err(ParseError::Empty), full_name("+"));
+		Ok(())
+	}
+
+	#[test]
+	fn rowlett_powers() -> Result<(), ParseError> {
+		// The names given by Rowlett, from his list of names for large numbers.
+		let names = [
+			(9, "gillion"), (12, "tetrillion"), (15, "pentillion"), (18, "hexillion"),
+			(21, "heptillion"), (24, "oktillion"), (27, "ennillion"), (30, "dekillion"),
+			(33, "hendekillion"), (36, "dodekillion"), (39, "trisdekillion"),
+			(42, "tetradekillion"), (57, "enneadekillion"), (60, "icosillion"),
+			(63, "icosikaihenillion"), (66, "icosikaidillion"), (69, "icosikaitrillion"),
+			(90, "triacontillion"), (120, "tetracontillion"), (270, "enneacontillion"),
+			(300, "hectillion"),
+		];
+		for (power, name) in names {
+			assert_eq!(format!("one {}", name), power_of_ten(power.to_string().as_str())?);
+		}
+
+		assert_eq!("one", power_of_ten("0")?);
+		assert_eq!("one hundred thousand", power_of_ten("5")?);
+		assert_eq!("ten million", power_of_ten("7")?);
+		assert_eq!("one hectahenillion", power_of_ten("303")?);
+		assert_eq!("one diacosillion", power_of_ten("600")?);
+		assert_eq!(Err(ParseError::InputTooLarge), power_of_ten("3000"));
+		assert_eq!(Err(ParseError::NegativeExponent), power_of_ten("-9"));
+		assert_eq!(Rowlett.full_name("1000000000000")?, Rowlett.power_of_ten("12")?);
+		Ok(())
+	}
+}